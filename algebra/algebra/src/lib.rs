@@ -1,3 +1,6 @@
+pub mod monoid;
+pub mod operator;
+
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 pub trait Zero: Sized + Add<Output = Self> {
@@ -17,3 +20,80 @@ pub trait Abelian: Zero + Neg<Output = Self> + Sub<Output = Self> {}
 pub trait Ring: Abelian + One {}
 
 pub trait Field: Abelian + Group {}
+
+pub trait Euclidean: Ring {
+    fn quotient(&self, rhs: &Self) -> Self;
+}
+
+pub trait Exponent: Copy {
+    fn to_u128(self) -> u128;
+}
+
+pub trait Bounded {
+    fn min_value() -> Self;
+    fn max_value() -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty), *) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    0
+                }
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+            }
+            impl One for $t {
+                fn one() -> Self {
+                    1
+                }
+                fn is_one(&self) -> bool {
+                    *self == 1
+                }
+            }
+            impl Bounded for $t {
+                fn min_value() -> Self {
+                    <$t>::MIN
+                }
+                fn max_value() -> Self {
+                    <$t>::MAX
+                }
+            }
+        )*
+    };
+}
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+macro_rules! impl_signed {
+    ($($t:ty), *) => {
+        $(
+            impl Abelian for $t {}
+            impl Ring for $t {}
+            impl Euclidean for $t {
+                fn quotient(&self, rhs: &Self) -> Self {
+                    self / rhs
+                }
+            }
+            impl Exponent for $t {
+                fn to_u128(self) -> u128 {
+                    assert!(self >= 0);
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+impl_signed!(i8, i16, i32, i64, i128, isize);
+macro_rules! impl_unsigned {
+    ($($t:ty), *) => {
+        $(
+            impl Exponent for $t {
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
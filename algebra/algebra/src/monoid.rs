@@ -0,0 +1,29 @@
+pub trait Semigroup {
+    type Value: Clone;
+    fn op(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
+}
+
+pub trait Monoid: Semigroup {
+    fn e() -> Self::Value;
+}
+
+pub trait CommutativeMonoid: Monoid {}
+
+pub trait Group: Monoid {
+    fn inv(val: &Self::Value) -> Self::Value;
+}
+
+pub trait AbelianGroup: Group + CommutativeMonoid {}
+
+pub trait Action: Monoid {
+    type Map: Monoid;
+    fn act(val: &mut Self::Value, map: &<Self::Map as Semigroup>::Value);
+}
+
+pub trait Semiring {
+    type Value: Clone;
+    fn zero() -> Self::Value;
+    fn one() -> Self::Value;
+    fn add(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
+    fn mul(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
+}
@@ -0,0 +1,471 @@
+use super::monoid::{AbelianGroup, Action, CommutativeMonoid, Group, Monoid, Semigroup, Semiring};
+use super::{Abelian, Bounded, Field, One, Zero};
+
+use std::{
+    marker::PhantomData,
+    ops::{BitXor, Rem, Sub},
+};
+
+pub struct Additive<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero> Semigroup for Additive<T> {
+    type Value = T;
+    fn op(lhs: &T, rhs: &T) -> T {
+        *lhs + *rhs
+    }
+}
+impl<T: Copy + Zero> Monoid for Additive<T> {
+    fn e() -> T {
+        T::zero()
+    }
+}
+impl<T: Copy + Zero> CommutativeMonoid for Additive<T> {}
+impl<T: Copy + Abelian> Group for Additive<T> {
+    fn inv(val: &T) -> T {
+        -*val
+    }
+}
+impl<T: Copy + Abelian> AbelianGroup for Additive<T> {}
+
+pub struct Multiplicative<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + One> Semigroup for Multiplicative<T> {
+    type Value = T;
+    fn op(lhs: &T, rhs: &T) -> T {
+        *lhs * *rhs
+    }
+}
+impl<T: Copy + One> Monoid for Multiplicative<T> {
+    fn e() -> T {
+        T::one()
+    }
+}
+impl<T: Copy + One> CommutativeMonoid for Multiplicative<T> {}
+impl<T: Copy + super::Group> Group for Multiplicative<T> {
+    fn inv(val: &T) -> T {
+        T::one() / *val
+    }
+}
+impl<T: Copy + super::Group> AbelianGroup for Multiplicative<T> {}
+
+pub struct Min<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + PartialOrd + Bounded> Semigroup for Min<T> {
+    type Value = T;
+    fn op(lhs: &T, rhs: &T) -> T {
+        if lhs <= rhs {
+            *lhs
+        } else {
+            *rhs
+        }
+    }
+}
+impl<T: Copy + PartialOrd + Bounded> Monoid for Min<T> {
+    fn e() -> T {
+        T::max_value()
+    }
+}
+impl<T: Copy + PartialOrd + Bounded> CommutativeMonoid for Min<T> {}
+
+pub struct Max<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + PartialOrd + Bounded> Semigroup for Max<T> {
+    type Value = T;
+    fn op(lhs: &T, rhs: &T) -> T {
+        if lhs >= rhs {
+            *lhs
+        } else {
+            *rhs
+        }
+    }
+}
+impl<T: Copy + PartialOrd + Bounded> Monoid for Max<T> {
+    fn e() -> T {
+        T::min_value()
+    }
+}
+impl<T: Copy + PartialOrd + Bounded> CommutativeMonoid for Max<T> {}
+
+pub struct Arithmetic<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + One> Semiring for Arithmetic<T> {
+    type Value = T;
+    fn zero() -> T {
+        T::zero()
+    }
+    fn one() -> T {
+        T::one()
+    }
+    fn add(lhs: &T, rhs: &T) -> T {
+        *lhs + *rhs
+    }
+    fn mul(lhs: &T, rhs: &T) -> T {
+        *lhs * *rhs
+    }
+}
+
+// Tropical products clamp at the bounds, so an overflowing path saturates to the
+// semiring zero instead of wrapping around.
+fn saturating_add<T: Copy + Zero + PartialOrd + Bounded + Sub<Output = T>>(lhs: T, rhs: T) -> T {
+    if rhs > T::zero() && lhs > T::max_value() - rhs {
+        T::max_value()
+    } else if rhs < T::zero() && lhs < T::min_value() - rhs {
+        T::min_value()
+    } else {
+        lhs + rhs
+    }
+}
+
+pub struct MinPlus<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + PartialOrd + Bounded + Sub<Output = T>> Semiring for MinPlus<T> {
+    type Value = T;
+    fn zero() -> T {
+        Min::e()
+    }
+    fn one() -> T {
+        T::zero()
+    }
+    fn add(lhs: &T, rhs: &T) -> T {
+        Min::op(lhs, rhs)
+    }
+    fn mul(lhs: &T, rhs: &T) -> T {
+        if *lhs == Self::zero() || *rhs == Self::zero() {
+            Self::zero()
+        } else {
+            saturating_add(*lhs, *rhs)
+        }
+    }
+}
+
+pub struct MaxPlus<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + PartialOrd + Bounded + Sub<Output = T>> Semiring for MaxPlus<T> {
+    type Value = T;
+    fn zero() -> T {
+        Max::e()
+    }
+    fn one() -> T {
+        T::zero()
+    }
+    fn add(lhs: &T, rhs: &T) -> T {
+        Max::op(lhs, rhs)
+    }
+    fn mul(lhs: &T, rhs: &T) -> T {
+        if *lhs == Self::zero() || *rhs == Self::zero() {
+            Self::zero()
+        } else {
+            saturating_add(*lhs, *rhs)
+        }
+    }
+}
+
+pub struct MaxMin<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + PartialOrd + Bounded> Semiring for MaxMin<T> {
+    type Value = T;
+    fn zero() -> T {
+        Max::e()
+    }
+    fn one() -> T {
+        Min::e()
+    }
+    fn add(lhs: &T, rhs: &T) -> T {
+        Max::op(lhs, rhs)
+    }
+    fn mul(lhs: &T, rhs: &T) -> T {
+        Min::op(lhs, rhs)
+    }
+}
+
+pub enum Boolean {}
+impl Semiring for Boolean {
+    type Value = bool;
+    fn zero() -> bool {
+        false
+    }
+    fn one() -> bool {
+        true
+    }
+    fn add(lhs: &bool, rhs: &bool) -> bool {
+        *lhs || *rhs
+    }
+    fn mul(lhs: &bool, rhs: &bool) -> bool {
+        *lhs && *rhs
+    }
+}
+
+pub struct Xor<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + BitXor<Output = T>> Semigroup for Xor<T> {
+    type Value = T;
+    fn op(lhs: &T, rhs: &T) -> T {
+        *lhs ^ *rhs
+    }
+}
+impl<T: Copy + Zero + BitXor<Output = T>> Monoid for Xor<T> {
+    fn e() -> T {
+        T::zero()
+    }
+}
+impl<T: Copy + Zero + BitXor<Output = T>> CommutativeMonoid for Xor<T> {}
+impl<T: Copy + Zero + BitXor<Output = T>> Group for Xor<T> {
+    fn inv(val: &T) -> T {
+        *val
+    }
+}
+impl<T: Copy + Zero + BitXor<Output = T>> AbelianGroup for Xor<T> {}
+
+pub struct Gcd<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + PartialOrd + Sub<Output = T> + Rem<Output = T>> Semigroup for Gcd<T> {
+    type Value = T;
+    fn op(lhs: &T, rhs: &T) -> T {
+        let (mut a, mut b) = (*lhs, *rhs);
+        while !b.is_zero() {
+            (a, b) = (b, a % b);
+        }
+        if a < T::zero() {
+            T::zero() - a
+        } else {
+            a
+        }
+    }
+}
+impl<T: Copy + Zero + PartialOrd + Sub<Output = T> + Rem<Output = T>> Monoid for Gcd<T> {
+    fn e() -> T {
+        T::zero()
+    }
+}
+impl<T: Copy + Zero + PartialOrd + Sub<Output = T> + Rem<Output = T>> CommutativeMonoid for Gcd<T> {}
+
+pub struct Affine<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + One> Semigroup for Affine<T> {
+    type Value = (T, T);
+    fn op(lhs: &(T, T), rhs: &(T, T)) -> (T, T) {
+        (rhs.0 * lhs.0, rhs.0 * lhs.1 + rhs.1)
+    }
+}
+impl<T: Copy + Zero + One> Monoid for Affine<T> {
+    fn e() -> (T, T) {
+        (T::one(), T::zero())
+    }
+}
+impl<T: Copy + Field> Group for Affine<T> {
+    fn inv(val: &(T, T)) -> (T, T) {
+        let inv = T::one() / val.0;
+        (inv, -(val.1 * inv))
+    }
+}
+
+pub struct Assign<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy> Semigroup for Assign<T> {
+    type Value = Option<T>;
+    fn op(lhs: &Option<T>, rhs: &Option<T>) -> Option<T> {
+        rhs.or(*lhs)
+    }
+}
+impl<T: Copy> Monoid for Assign<T> {
+    fn e() -> Option<T> {
+        None
+    }
+}
+
+pub struct RangeAddRangeSum<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + One> Semigroup for RangeAddRangeSum<T> {
+    type Value = (T, T);
+    fn op(lhs: &(T, T), rhs: &(T, T)) -> (T, T) {
+        (lhs.0 + rhs.0, lhs.1 + rhs.1)
+    }
+}
+impl<T: Copy + Zero + One> Monoid for RangeAddRangeSum<T> {
+    fn e() -> (T, T) {
+        (T::zero(), T::zero())
+    }
+}
+impl<T: Copy + Zero + One> Action for RangeAddRangeSum<T> {
+    type Map = Additive<T>;
+    fn act(val: &mut (T, T), map: &T) {
+        val.0 = val.0 + *map * val.1;
+    }
+}
+
+pub struct RangeAddRangeMin<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + PartialOrd> Semigroup for RangeAddRangeMin<T> {
+    type Value = Option<T>;
+    fn op(lhs: &Option<T>, rhs: &Option<T>) -> Option<T> {
+        match (lhs, rhs) {
+            (Some(a), Some(b)) => Some(if a <= b { *a } else { *b }),
+            _ => lhs.or(*rhs),
+        }
+    }
+}
+impl<T: Copy + Zero + PartialOrd> Monoid for RangeAddRangeMin<T> {
+    fn e() -> Option<T> {
+        None
+    }
+}
+impl<T: Copy + Zero + PartialOrd> Action for RangeAddRangeMin<T> {
+    type Map = Additive<T>;
+    fn act(val: &mut Option<T>, map: &T) {
+        if let Some(v) = val {
+            *v = *v + *map;
+        }
+    }
+}
+
+pub struct RangeAddRangeMax<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + PartialOrd> Semigroup for RangeAddRangeMax<T> {
+    type Value = Option<T>;
+    fn op(lhs: &Option<T>, rhs: &Option<T>) -> Option<T> {
+        match (lhs, rhs) {
+            (Some(a), Some(b)) => Some(if a >= b { *a } else { *b }),
+            _ => lhs.or(*rhs),
+        }
+    }
+}
+impl<T: Copy + Zero + PartialOrd> Monoid for RangeAddRangeMax<T> {
+    fn e() -> Option<T> {
+        None
+    }
+}
+impl<T: Copy + Zero + PartialOrd> Action for RangeAddRangeMax<T> {
+    type Map = Additive<T>;
+    fn act(val: &mut Option<T>, map: &T) {
+        if let Some(v) = val {
+            *v = *v + *map;
+        }
+    }
+}
+
+pub struct RangeAssignRangeSum<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + One> Semigroup for RangeAssignRangeSum<T> {
+    type Value = (T, T);
+    fn op(lhs: &(T, T), rhs: &(T, T)) -> (T, T) {
+        RangeAddRangeSum::op(lhs, rhs)
+    }
+}
+impl<T: Copy + Zero + One> Monoid for RangeAssignRangeSum<T> {
+    fn e() -> (T, T) {
+        RangeAddRangeSum::e()
+    }
+}
+impl<T: Copy + Zero + One> Action for RangeAssignRangeSum<T> {
+    type Map = Assign<T>;
+    fn act(val: &mut (T, T), map: &Option<T>) {
+        if let Some(x) = *map {
+            val.0 = x * val.1;
+        }
+    }
+}
+
+pub struct RangeAssignRangeMin<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + PartialOrd + Bounded> Semigroup for RangeAssignRangeMin<T> {
+    type Value = T;
+    fn op(lhs: &T, rhs: &T) -> T {
+        Min::op(lhs, rhs)
+    }
+}
+impl<T: Copy + PartialOrd + Bounded> Monoid for RangeAssignRangeMin<T> {
+    fn e() -> T {
+        Min::e()
+    }
+}
+impl<T: Copy + PartialOrd + Bounded> Action for RangeAssignRangeMin<T> {
+    type Map = Assign<T>;
+    fn act(val: &mut T, map: &Option<T>) {
+        if let Some(x) = *map {
+            *val = x;
+        }
+    }
+}
+
+pub struct RangeAssignRangeMax<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + PartialOrd + Bounded> Semigroup for RangeAssignRangeMax<T> {
+    type Value = T;
+    fn op(lhs: &T, rhs: &T) -> T {
+        Max::op(lhs, rhs)
+    }
+}
+impl<T: Copy + PartialOrd + Bounded> Monoid for RangeAssignRangeMax<T> {
+    fn e() -> T {
+        Max::e()
+    }
+}
+impl<T: Copy + PartialOrd + Bounded> Action for RangeAssignRangeMax<T> {
+    type Map = Assign<T>;
+    fn act(val: &mut T, map: &Option<T>) {
+        if let Some(x) = *map {
+            *val = x;
+        }
+    }
+}
+
+pub struct RangeAffineRangeSum<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Copy + Zero + One> Semigroup for RangeAffineRangeSum<T> {
+    type Value = (T, T);
+    fn op(lhs: &(T, T), rhs: &(T, T)) -> (T, T) {
+        RangeAddRangeSum::op(lhs, rhs)
+    }
+}
+impl<T: Copy + Zero + One> Monoid for RangeAffineRangeSum<T> {
+    fn e() -> (T, T) {
+        RangeAddRangeSum::e()
+    }
+}
+impl<T: Copy + Zero + One> Action for RangeAffineRangeSum<T> {
+    type Map = Affine<T>;
+    fn act(val: &mut (T, T), map: &(T, T)) {
+        val.0 = map.0 * val.0 + map.1 * val.1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MaxPlus, MinPlus};
+    use crate::monoid::Semiring;
+
+    #[test]
+    fn tropical_mul_saturates() {
+        type Min = MinPlus<i64>;
+        type Max = MaxPlus<i64>;
+        assert_eq!(Min::mul(&3, &-5), -2);
+        assert_eq!(Min::mul(&(i64::MAX - 1), &5), Min::zero());
+        assert_eq!(Min::mul(&Min::zero(), &-5), Min::zero());
+        assert_eq!(Min::mul(&(i64::MIN + 1), &-5), i64::MIN);
+        assert_eq!(Max::mul(&3, &-5), -2);
+        assert_eq!(Max::mul(&(i64::MIN + 1), &-5), Max::zero());
+        assert_eq!(Max::mul(&Max::zero(), &5), Max::zero());
+        assert_eq!(Max::mul(&(i64::MAX - 1), &5), i64::MAX);
+        assert_eq!(MinPlus::<u32>::mul(&u32::MAX, &0), u32::MAX);
+        assert_eq!(MinPlus::<u32>::mul(&(u32::MAX - 3), &7), u32::MAX);
+    }
+}
@@ -1,31 +1,241 @@
 use super::GF;
 
+use std::cell::RefCell;
+
 pub struct Combinatorics<const MOD: u32> {
-    factorials: Vec<GF<MOD>>,
-    inv_factorials: Vec<GF<MOD>>,
+    factorials: RefCell<Vec<GF<MOD>>>,
+    inv_factorials: RefCell<Vec<GF<MOD>>>,
 }
 
 impl<const MOD: u32> Combinatorics<MOD> {
     pub fn new(n: usize) -> Self {
-        let mut factorials = Vec::with_capacity(n);
-        factorials.push(GF::<MOD>::new(1));
-        for i in 1..=n as u32 {
-            factorials.push(factorials.last().unwrap() * GF::<MOD>::new(i));
+        let res = Self {
+            factorials: RefCell::new(vec![GF::new(1)]),
+            inv_factorials: RefCell::new(vec![GF::new(1)]),
+        };
+        res.reserve(n);
+        res
+    }
+
+    fn reserve(&self, n: usize) {
+        let n = n.min(MOD as usize - 1);
+        let mut factorials = self.factorials.borrow_mut();
+        let len = factorials.len();
+        if n < len {
+            return;
+        }
+        let m = (2 * len).max(n + 1).min(MOD as usize);
+        for i in len..m {
+            let f = factorials[i - 1] * GF::new(i as u32);
+            factorials.push(f);
+        }
+        let mut inv_factorials = self.inv_factorials.borrow_mut();
+        let mut suffix = vec![factorials[m - 1].inv()];
+        for i in (len + 1..m).rev() {
+            let f = suffix.last().unwrap() * GF::new(i as u32);
+            suffix.push(f);
+        }
+        inv_factorials.extend(suffix.iter().rev());
+    }
+
+    pub fn fact(&self, n: usize) -> GF<MOD> {
+        if n >= MOD as usize {
+            return GF::new(0);
+        }
+        self.reserve(n);
+        self.factorials.borrow()[n]
+    }
+
+    pub fn inv_fact(&self, n: usize) -> GF<MOD> {
+        assert!(n < MOD as usize);
+        self.reserve(n);
+        self.inv_factorials.borrow()[n]
+    }
+
+    pub fn inv(&self, n: usize) -> GF<MOD> {
+        let n = n % MOD as usize;
+        assert!(n > 0);
+        self.fact(n - 1) * self.inv_fact(n)
+    }
+
+    pub fn binom(&self, mut n: usize, mut r: usize) -> GF<MOD> {
+        let mut res = GF::new(1);
+        while r > 0 {
+            let (a, b) = (n % MOD as usize, r % MOD as usize);
+            if a < b {
+                return GF::new(0);
+            }
+            res *= self.fact(a) * self.inv_fact(b) * self.inv_fact(a - b);
+            n /= MOD as usize;
+            r /= MOD as usize;
+        }
+        res
+    }
+
+    pub fn perm(&self, n: usize, r: usize) -> GF<MOD> {
+        let m = n % MOD as usize;
+        if r > m {
+            return GF::new(0);
+        }
+        self.fact(m) * self.inv_fact(m - r)
+    }
+
+    pub fn multinomial(&self, k: &[usize]) -> GF<MOD> {
+        let mut n = 0;
+        let mut res = GF::new(1);
+        for &k in k {
+            n += k;
+            res *= self.binom(n, k);
+        }
+        res
+    }
+
+    pub fn homogeneous(&self, n: usize, r: usize) -> GF<MOD> {
+        if n == 0 {
+            return GF::new((r == 0) as u32);
+        }
+        self.binom(n + r - 1, r)
+    }
+
+    pub fn catalan(&self, n: usize) -> GF<MOD> {
+        self.binom(2 * n, n) - self.binom(2 * n, n + 1)
+    }
+}
+
+pub fn stirling_first_table<const MOD: u32>(n: usize) -> Vec<Vec<GF<MOD>>> {
+    let mut res = vec![vec![GF::new(1)]];
+    for i in 0..n {
+        let mut row = vec![GF::new(0); i + 2];
+        for (j, &s) in res[i].iter().enumerate() {
+            row[j + 1] += s;
+            row[j] -= s * GF::new(i as u32);
+        }
+        res.push(row);
+    }
+    res
+}
+
+pub fn stirling_second_table<const MOD: u32>(n: usize) -> Vec<Vec<GF<MOD>>> {
+    let mut res = vec![vec![GF::new(1)]];
+    for i in 0..n {
+        let mut row = vec![GF::new(0); i + 2];
+        for (j, &s) in res[i].iter().enumerate() {
+            row[j + 1] += s;
+            row[j] += s * GF::new(j as u32);
+        }
+        res.push(row);
+    }
+    res
+}
+
+pub fn bell_table<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    let mut res = vec![GF::new(1)];
+    let mut row = vec![GF::new(1)];
+    for _ in 0..n {
+        let mut next = vec![*row.last().unwrap()];
+        for &a in &row {
+            next.push(*next.last().unwrap() + a);
+        }
+        res.push(next[0]);
+        row = next;
+    }
+    res
+}
+
+pub fn partition_table<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    let mut res = vec![GF::new(0); n + 1];
+    res[0] = GF::new(1);
+    for i in 1..=n {
+        for k in 1.. {
+            let p = k * (3 * k - 1) / 2;
+            if p > i {
+                break;
+            }
+            let mut s = res[i - p];
+            if p + k <= i {
+                s += res[i - p - k];
+            }
+            if k % 2 == 1 {
+                res[i] += s;
+            } else {
+                res[i] -= s;
+            }
+        }
+    }
+    res
+}
+
+pub fn bernoulli_table<const MOD: u32>(n: usize) -> Vec<GF<MOD>> {
+    let comb = Combinatorics::new(n + 1);
+    let mut res = vec![GF::new(1)];
+    for m in 1..=n {
+        let s = (0..m)
+            .map(|k| comb.binom(m + 1, k) * res[k])
+            .sum::<GF<MOD>>();
+        res.push(-s * comb.inv(m + 1));
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Combinatorics;
+    use crate::GF;
+
+    fn check<const MOD: u32>() {
+        const N: usize = 80;
+        let comb = Combinatorics::<MOD>::new(0);
+        let mut pascal = vec![vec![GF::<MOD>::new(1)]];
+        for n in 1..=N {
+            let mut row = vec![GF::new(1); n + 1];
+            for r in 1..n {
+                row[r] = pascal[n - 1][r - 1] + pascal[n - 1][r];
+            }
+            pascal.push(row);
         }
-        let mut inv_factorials = Vec::with_capacity(n);
-        inv_factorials.push(factorials.last().unwrap().inv());
-        for i in (1..=n as u32).rev() {
-            inv_factorials.push(inv_factorials.last().unwrap() * GF::<MOD>::new(i));
+        let mut fact = GF::new(1);
+        for n in 0..=N {
+            assert_eq!(comb.fact(n), fact);
+            if n < MOD as usize {
+                assert_eq!(comb.inv_fact(n) * fact, GF::new(1));
+            }
+            if n % MOD as usize != 0 {
+                assert_eq!(comb.inv(n) * GF::new(n as u32), GF::new(1));
+            }
+            for r in 0..=N {
+                let binom = pascal[n].get(r).copied().unwrap_or(GF::new(0));
+                assert_eq!(comb.binom(n, r), binom, "binom({n}, {r}) mod {MOD}");
+                let perm = (0..r)
+                    .map(|i| GF::new((n as u32).saturating_sub(i as u32)))
+                    .product();
+                assert_eq!(comb.perm(n, r), perm, "perm({n}, {r}) mod {MOD}");
+                if n > 0 && n + r <= N + 1 {
+                    assert_eq!(comb.homogeneous(n, r), pascal[n + r - 1][r]);
+                }
+            }
+            for k in 0..=n {
+                for l in 0..=n - k {
+                    let binom = pascal[n][k] * pascal[n - k][l];
+                    assert_eq!(comb.multinomial(&[k, l, n - k - l]), binom);
+                }
+            }
+            fact *= GF::new(n as u32 + 1);
         }
-        inv_factorials.reverse();
+        assert_eq!(comb.homogeneous(0, 0), GF::new(1));
+        assert_eq!(comb.homogeneous(0, 3), GF::new(0));
 
-        Self {
-            factorials,
-            inv_factorials,
+        let mut catalan = vec![GF::<MOD>::new(1)];
+        for n in 0..N / 2 {
+            assert_eq!(comb.catalan(n), catalan[n]);
+            catalan.push((0..=n).map(|i| catalan[i] * catalan[n - i]).sum());
         }
     }
 
-    pub fn binom(&self, n: usize, r: usize) -> GF<MOD> {
-        self.factorials[n] * self.inv_factorials[r] * self.inv_factorials[n - r]
+    #[test]
+    fn matches_pascal_triangle() {
+        check::<2>();
+        check::<7>();
+        check::<13>();
+        check::<998_244_353>();
     }
 }
@@ -1,6 +1,10 @@
 pub mod combinatorics;
+pub mod montgomery;
 
-use algebra::{Abelian, Field, Group, One, Ring, Zero};
+use algebra::{Abelian, Euclidean, Exponent, Field, Group, One, Ring, Zero};
+use integer::residue::{
+    discrete_log, kth_root_mod, multiplicative_order, primitive_root, sqrt_mod,
+};
 
 use std::{
     fmt::{Debug, Display},
@@ -18,9 +22,14 @@ impl<const MOD: u32> GF<MOD> {
         Self { value: value % MOD }
     }
 
-    pub fn pow(&self, mut exp: u32) -> Self {
+    pub fn pow<E: Exponent>(&self, exp: E) -> Self {
+        let exp = exp.to_u128();
+        if self.value == 0 {
+            return if exp == 0 { Self::one() } else { Self::zero() };
+        }
+        let mut exp = exp % (MOD - 1) as u128;
         let mut res = Self::new(1);
-        let mut base = self.clone();
+        let mut base = *self;
         while exp > 0 {
             if exp & 1 == 1 {
                 res *= base;
@@ -31,9 +40,46 @@ impl<const MOD: u32> GF<MOD> {
         res
     }
 
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative and `self` is zero.
+    pub fn pow_signed(&self, exp: i64) -> Self {
+        if exp < 0 {
+            assert!(self.value != 0, "zero has no negative powers");
+            self.inv().pow(exp.unsigned_abs())
+        } else {
+            self.pow(exp as u64)
+        }
+    }
+
+    pub fn pow_decimal(&self, exp: &str) -> Self {
+        exp.bytes()
+            .fold(Self::one(), |acc, d| acc.pow(10u32) * self.pow(d - b'0'))
+    }
+
     pub fn inv(&self) -> Self {
         self.pow(MOD - 2)
     }
+
+    pub fn sqrt(&self) -> Option<Self> {
+        sqrt_mod(self.value as u64, MOD as u64).map(|x| Self::new(x as u32))
+    }
+
+    pub fn kth_root(&self, k: u64) -> Option<Self> {
+        kth_root_mod(self.value as u64, k, MOD as u64).map(|x| Self::new(x as u32))
+    }
+
+    pub fn log(&self, base: Self) -> Option<u64> {
+        discrete_log(base.value as u64, self.value as u64, MOD as u64)
+    }
+
+    pub fn order(&self) -> Option<u64> {
+        multiplicative_order(self.value as u64, MOD as u64)
+    }
+
+    pub fn primitive_root() -> Self {
+        Self::new(primitive_root(MOD as u64) as u32)
+    }
 }
 
 #[macro_export]
@@ -112,6 +158,11 @@ impl<const MOD: u32> One for GF<MOD> {
 impl<const MOD: u32> Group for GF<MOD> {}
 impl<const MOD: u32> Abelian for GF<MOD> {}
 impl<const MOD: u32> Ring for GF<MOD> {}
+impl<const MOD: u32> Euclidean for GF<MOD> {
+    fn quotient(&self, rhs: &Self) -> Self {
+        self / rhs
+    }
+}
 impl<const MOD: u32> Field for GF<MOD> {}
 
 impl<const MOD: u32> AddAssign<GF<MOD>> for GF<MOD> {
@@ -206,3 +257,62 @@ impl<'a, const MOD: u32> Product<&'a Self> for GF<MOD> {
         iter.copied().product()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{montgomery::MontgomeryGF, GF};
+
+    type Fp = GF<998_244_353>;
+    type Mfp = MontgomeryGF<998_244_353>;
+
+    #[test]
+    fn pow_matches_repeated_mul() {
+        for a in [0, 1, 2, 3, 998_244_352, 123_456_789] {
+            let (x, y) = (Fp::new(a), Mfp::new(a));
+            let mut expected = Fp::new(1);
+            for e in 0..100u64 {
+                assert_eq!(x.pow(e), expected);
+                assert_eq!(x.pow(e as u128), expected);
+                assert_eq!(x.pow(e as u32), expected);
+                assert_eq!(y.pow(e).value(), expected.value);
+                assert_eq!(x.pow_decimal(&e.to_string()), expected);
+                assert_eq!(y.pow_decimal(&e.to_string()).value(), expected.value);
+                assert_eq!(x.pow_signed(e as i64), expected);
+                expected *= x;
+            }
+            // exponents beyond the group order, where reduction by MOD - 1 kicks in
+            let e = 998_244_352u128 * 1_000_000_007 + 5;
+            assert_eq!(x.pow(e), x.pow(5u32));
+            assert_eq!(y.pow(e).value(), x.pow(5u32).value);
+            assert_eq!(x.pow_decimal(&e.to_string()), x.pow(5u32));
+            assert_eq!(x.pow(u128::MAX), x.pow(u128::MAX % 998_244_352));
+        }
+    }
+
+    #[test]
+    fn pow_signed_inverts() {
+        for a in [1, 2, 3, 998_244_352, 123_456_789] {
+            let (x, y) = (Fp::new(a), Mfp::new(a));
+            for e in 0..50i64 {
+                assert_eq!(x.pow_signed(-e) * x.pow_signed(e), Fp::new(1));
+                assert_eq!(x.pow_signed(-e), x.inv().pow(e as u64));
+                assert_eq!(y.pow_signed(-e).value(), x.pow_signed(-e).value);
+            }
+            assert_eq!(x.pow_signed(i64::MIN), x.inv().pow(1u128 << 63));
+        }
+        assert_eq!(Fp::new(0).pow_signed(0), Fp::new(1));
+        assert_eq!(Mfp::new(0).pow_signed(0).value(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero has no negative powers")]
+    fn pow_signed_zero_negative() {
+        Fp::new(0).pow_signed(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero has no negative powers")]
+    fn montgomery_pow_signed_zero_negative() {
+        Mfp::new(0).pow_signed(-1);
+    }
+}
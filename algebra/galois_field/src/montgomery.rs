@@ -0,0 +1,289 @@
+use algebra::{Abelian, Euclidean, Exponent, Field, Group, One, Ring, Zero};
+
+use std::{
+    fmt::{Debug, Display},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MontgomeryGF<const MOD: u32> {
+    value: u32,
+}
+
+impl<const MOD: u32> MontgomeryGF<MOD> {
+    const MOD_INV: u32 = {
+        assert!(MOD & 1 == 1);
+        let mut inv = MOD;
+        let mut i = 0;
+        while i < 4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(MOD.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv
+    };
+    const R2: u32 = ((1u128 << 64) % MOD as u128) as u32;
+
+    pub fn new(value: u32) -> Self {
+        Self {
+            value: Self::reduce((value % MOD) as u64 * Self::R2 as u64),
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        Self::reduce(self.value as u64)
+    }
+
+    pub fn pow<E: Exponent>(&self, exp: E) -> Self {
+        let exp = exp.to_u128();
+        if self.value == 0 {
+            return if exp == 0 { Self::one() } else { Self::zero() };
+        }
+        let mut exp = exp % (MOD - 1) as u128;
+        let mut res = Self::new(1);
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        res
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative and `self` is zero.
+    pub fn pow_signed(&self, exp: i64) -> Self {
+        if exp < 0 {
+            assert!(self.value != 0, "zero has no negative powers");
+            self.inv().pow(exp.unsigned_abs())
+        } else {
+            self.pow(exp as u64)
+        }
+    }
+
+    pub fn pow_decimal(&self, exp: &str) -> Self {
+        exp.bytes()
+            .fold(Self::one(), |acc, d| acc.pow(10u32) * self.pow(d - b'0'))
+    }
+
+    pub fn inv(&self) -> Self {
+        self.pow(MOD - 2)
+    }
+
+    fn reduce(t: u64) -> u32 {
+        let m = (t as u32).wrapping_mul(Self::MOD_INV);
+        let mn = m as u64 * MOD as u64;
+        let (v, borrow) = ((t >> 32) as u32).overflowing_sub((mn >> 32) as u32);
+        if borrow {
+            v.wrapping_add(MOD)
+        } else {
+            v
+        }
+    }
+}
+
+macro_rules! montgomery_gf_new_from_signed {
+    ($($t:ty), *) => {
+        $(
+            impl<const MOD: u32> From<$t> for MontgomeryGF<MOD> {
+                fn from(x: $t) -> Self {
+                    Self::new((x as i128).rem_euclid(MOD as i128) as u32)
+                }
+            }
+        )*
+    };
+}
+montgomery_gf_new_from_signed!(i8, i16, i32, i64, i128, isize);
+macro_rules! montgomery_gf_new_from_unsigned {
+    ($($t:ty), *) => {
+        $(
+            impl<const MOD: u32> From<$t> for MontgomeryGF<MOD> {
+                fn from(x: $t) -> Self {
+                    Self::new((x as u128 % MOD as u128) as u32)
+                }
+            }
+        )*
+    };
+}
+montgomery_gf_new_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl<const MOD: u32> Debug for MontgomeryGF<MOD> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+impl<const MOD: u32> Display for MontgomeryGF<MOD> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<const MOD: u32> Neg for MontgomeryGF<MOD> {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        if self.value > 0 {
+            self.value = MOD - self.value;
+        }
+        self
+    }
+}
+impl<const MOD: u32> Zero for MontgomeryGF<MOD> {
+    fn zero() -> Self {
+        Self { value: 0 }
+    }
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+impl<const MOD: u32> One for MontgomeryGF<MOD> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+}
+impl<const MOD: u32> Group for MontgomeryGF<MOD> {}
+impl<const MOD: u32> Abelian for MontgomeryGF<MOD> {}
+impl<const MOD: u32> Ring for MontgomeryGF<MOD> {}
+impl<const MOD: u32> Euclidean for MontgomeryGF<MOD> {
+    fn quotient(&self, rhs: &Self) -> Self {
+        self / rhs
+    }
+}
+impl<const MOD: u32> Field for MontgomeryGF<MOD> {}
+
+impl<const MOD: u32> AddAssign<MontgomeryGF<MOD>> for MontgomeryGF<MOD> {
+    fn add_assign(&mut self, rhs: MontgomeryGF<MOD>) {
+        let (v, carry) = self.value.overflowing_add(rhs.value);
+        self.value = if carry || v >= MOD {
+            v.wrapping_sub(MOD)
+        } else {
+            v
+        };
+    }
+}
+impl<const MOD: u32> SubAssign<MontgomeryGF<MOD>> for MontgomeryGF<MOD> {
+    fn sub_assign(&mut self, rhs: MontgomeryGF<MOD>) {
+        let (v, borrow) = self.value.overflowing_sub(rhs.value);
+        self.value = if borrow { v.wrapping_add(MOD) } else { v };
+    }
+}
+impl<const MOD: u32> MulAssign<MontgomeryGF<MOD>> for MontgomeryGF<MOD> {
+    fn mul_assign(&mut self, rhs: MontgomeryGF<MOD>) {
+        self.value = Self::reduce(self.value as u64 * rhs.value as u64);
+    }
+}
+impl<const MOD: u32> DivAssign<MontgomeryGF<MOD>> for MontgomeryGF<MOD> {
+    fn div_assign(&mut self, rhs: MontgomeryGF<MOD>) {
+        self.mul_assign(rhs.inv());
+    }
+}
+macro_rules! montgomery_gf_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+    )*) => {$(
+        impl<const MOD: u32> $trait_assign<&MontgomeryGF<MOD>> for MontgomeryGF<MOD> {
+            fn $fn_assign(&mut self, rhs: &MontgomeryGF<MOD>) {
+                self.$fn_assign(*rhs);
+            }
+        }
+        impl<const MOD: u32, T: Into<MontgomeryGF<MOD>>> $trait<T> for MontgomeryGF<MOD> {
+            type Output = MontgomeryGF<MOD>;
+            fn $fn(mut self, rhs: T) -> Self::Output {
+                self.$fn_assign(rhs.into());
+                self
+            }
+        }
+        impl<const MOD: u32> $trait<&MontgomeryGF<MOD>> for MontgomeryGF<MOD> {
+            type Output = MontgomeryGF<MOD>;
+            fn $fn(self, rhs: &MontgomeryGF<MOD>) -> Self::Output {
+                self.$fn(*rhs)
+            }
+        }
+        impl<const MOD: u32, T: Into<MontgomeryGF<MOD>>> $trait<T> for &MontgomeryGF<MOD> {
+            type Output = MontgomeryGF<MOD>;
+            fn $fn(self, rhs: T) -> Self::Output {
+                (*self).$fn(rhs.into())
+            }
+        }
+        impl<const MOD: u32> $trait<&MontgomeryGF<MOD>> for &MontgomeryGF<MOD> {
+            type Output = MontgomeryGF<MOD>;
+            fn $fn(self, rhs: &MontgomeryGF<MOD>) -> Self::Output {
+                (*self).$fn(*rhs)
+            }
+        }
+    )*};
+}
+montgomery_gf_ops! {
+    Add, AddAssign, add, add_assign,
+    Sub, SubAssign, sub, sub_assign,
+    Mul, MulAssign, mul, mul_assign,
+    Div, DivAssign, div, div_assign,
+}
+
+impl<const MOD: u32> Sum for MontgomeryGF<MOD> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, a| acc + a)
+    }
+}
+impl<'a, const MOD: u32> Sum<&'a Self> for MontgomeryGF<MOD> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+impl<const MOD: u32> Product for MontgomeryGF<MOD> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, a| acc * a)
+    }
+}
+impl<'a, const MOD: u32> Product<&'a Self> for MontgomeryGF<MOD> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MontgomeryGF;
+    use crate::GF;
+    use xorshift::XorShift;
+
+    fn check<const MOD: u32>() {
+        let mut rng = XorShift::default();
+        let mut values = vec![0, 1, 2, MOD - 1, MOD / 2];
+        values.extend((0..200).map(|_| rng.below(MOD as u64) as u32));
+        for &a in &values {
+            let (x, y) = (GF::<MOD>::new(a), MontgomeryGF::<MOD>::new(a));
+            assert_eq!(y.value(), a);
+            assert_eq!((-y).to_string(), (-x).to_string());
+            if a != 0 {
+                assert_eq!(y.inv().to_string(), x.inv().to_string());
+            }
+            let e = rng.next_u64();
+            assert_eq!(y.pow(e).to_string(), x.pow(e).to_string());
+            for &b in values.iter().take(20) {
+                let (u, v) = (GF::<MOD>::new(b), MontgomeryGF::<MOD>::new(b));
+                assert_eq!((y + v).to_string(), (x + u).to_string());
+                assert_eq!((y - v).to_string(), (x - u).to_string());
+                assert_eq!((y * v).to_string(), (x * u).to_string());
+                if b != 0 {
+                    assert_eq!((y / v).to_string(), (x / u).to_string());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_gf() {
+        check::<998_244_353>();
+        check::<1_000_000_007>();
+        check::<2_147_483_647>();
+        check::<3>();
+    }
+}
@@ -0,0 +1,192 @@
+use super::{euclid::inv_mod, prime::factorize, residue::mul_mod};
+
+use std::cell::RefCell;
+
+const TABLE_LIMIT: usize = 1 << 20;
+
+struct PrimePower {
+    p: u64,
+    e: u32,
+    pe: u64,
+    factorials: RefCell<Vec<u64>>,
+}
+
+impl PrimePower {
+    fn new(p: u64, e: u32) -> Self {
+        Self {
+            p,
+            e,
+            pe: p.pow(e),
+            factorials: RefCell::new(vec![1]),
+        }
+    }
+
+    // product of 1..=n skipping multiples of p, modulo p^e, for n < p^e
+    fn partial_factorial(&self, n: u64) -> u64 {
+        let mut factorials = self.factorials.borrow_mut();
+        let step = |f: u64, i: u64| {
+            if i.is_multiple_of(self.p) {
+                f
+            } else {
+                mul_mod(f, i, self.pe)
+            }
+        };
+        while factorials.len() <= (n as usize).min(TABLE_LIMIT) {
+            let i = factorials.len();
+            let f = step(factorials[i - 1], i as u64);
+            factorials.push(f);
+        }
+        if let Some(&f) = factorials.get(n as usize) {
+            return f;
+        }
+        let start = factorials.len() as u64;
+        (start..=n).fold(factorials[start as usize - 1], step)
+    }
+
+    fn factorial(&self, mut n: u64) -> (u64, u64) {
+        // product of all units modulo p^e (generalized Wilson's theorem)
+        let last = if self.p == 2 && self.e >= 3 {
+            1
+        } else {
+            self.pe - 1
+        };
+        let mut res = 1 % self.pe;
+        let mut count = 0;
+        while n > 0 {
+            if (n / self.pe) % 2 == 1 {
+                res = mul_mod(res, last, self.pe);
+            }
+            res = mul_mod(res, self.partial_factorial(n % self.pe), self.pe);
+            n /= self.p;
+            count += n;
+        }
+        (res, count)
+    }
+
+    fn binom(&self, n: u64, r: u64) -> u64 {
+        let (a, x) = self.factorial(n);
+        let (b, y) = self.factorial(r);
+        let (c, z) = self.factorial(n - r);
+        let count = x - y - z;
+        if count >= self.e as u64 {
+            return 0;
+        }
+        let res = mul_mod(
+            a,
+            inv_mod(mul_mod(b, c, self.pe), self.pe).unwrap(),
+            self.pe,
+        );
+        mul_mod(res, self.p.pow(count as u32), self.pe)
+    }
+}
+
+/// Binomial coefficients modulo an arbitrary modulus, combined by CRT over its
+/// prime power factors.
+///
+/// For each factor `p^e`, factorials of residues below `p^e` are cached lazily up
+/// to the largest residue queried, capped at `2^20` entries (8 MiB) per factor.
+/// Residues beyond the cap are recomputed on every query, so a query costs
+/// `O(min(p^e, n) log_p n)` time in the worst case.
+pub struct BinomialMod {
+    modulus: u64,
+    prime_powers: Vec<PrimePower>,
+    coefficients: Vec<u64>,
+}
+
+impl BinomialMod {
+    pub fn new(modulus: u64) -> Self {
+        assert!(modulus >= 1);
+        let prime_powers = factorize(modulus)
+            .into_iter()
+            .map(|(p, e)| PrimePower::new(p, e))
+            .collect::<Vec<_>>();
+        let coefficients = prime_powers
+            .iter()
+            .map(|pp| {
+                let rest = modulus / pp.pe;
+                mul_mod(rest, inv_mod(rest % pp.pe, pp.pe).unwrap(), modulus)
+            })
+            .collect();
+        Self {
+            modulus,
+            prime_powers,
+            coefficients,
+        }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    pub fn binom(&self, n: u64, r: u64) -> u64 {
+        if r > n {
+            return 0;
+        }
+        self.prime_powers
+            .iter()
+            .zip(&self.coefficients)
+            .fold(0, |acc, (pp, &c)| {
+                let x = mul_mod(pp.binom(n, r), c, self.modulus);
+                ((acc as u128 + x as u128) % self.modulus as u128) as u64
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BinomialMod;
+
+    #[test]
+    fn matches_pascal_triangle() {
+        for modulus in [
+            1,
+            2,
+            27,
+            360,
+            1024,
+            999_983,
+            1_000_000_007,
+            2 * 1_000_000_007,
+        ] {
+            let binom = BinomialMod::new(modulus);
+            let mut row = vec![1 % modulus];
+            for n in 0..80u64 {
+                for (r, &c) in row.iter().enumerate() {
+                    assert_eq!(binom.binom(n, r as u64), c);
+                }
+                assert_eq!(binom.binom(n, n + 1), 0);
+                let mut next = vec![1 % modulus; row.len() + 1];
+                for r in 1..row.len() {
+                    next[r] = (row[r - 1] + row[r]) % modulus;
+                }
+                row = next;
+            }
+        }
+    }
+
+    #[test]
+    fn large_prime_power() {
+        for (modulus, ns) in [
+            (
+                10_007 * 10_007,
+                vec![10_007 * 10_007 + 3, 5 * 10_007 * 10_007 + 2_000_000],
+            ),
+            (3u64.pow(13) * 7, vec![2_000_000, 3 * 3u64.pow(13) + 12_345]),
+            (1 << 21, vec![1_500_000, 3 << 21 | 5]),
+        ] {
+            let binom = BinomialMod::new(modulus);
+            let m = modulus as u128;
+            for n in ns {
+                let n = n as u128;
+                assert_eq!(binom.binom(n as u64, 0), 1);
+                assert_eq!(binom.binom(n as u64, 1) as u128, n % m);
+                assert_eq!(binom.binom(n as u64, 2) as u128, n * (n - 1) / 2 % m);
+                assert_eq!(
+                    binom.binom(n as u64, 3) as u128,
+                    n * (n - 1) / 2 * (n - 2) / 3 % m
+                );
+                assert_eq!(binom.binom(n as u64, n as u64 - 1) as u128, n % m);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use algebra::{Abelian, One, Ring, Zero};
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Checked<T> {
+    value: Option<T>,
+}
+
+impl<T> Checked<T> {
+    pub fn new(value: T) -> Self {
+        Self { value: Some(value) }
+    }
+
+    pub fn value(self) -> Option<T> {
+        self.value
+    }
+
+    pub fn is_overflowed(&self) -> bool {
+        self.value.is_none()
+    }
+}
+
+impl<T: Debug> Debug for Checked<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{:?}", value),
+            None => write!(f, "overflow"),
+        }
+    }
+}
+
+macro_rules! impl_checked {
+    ($($t:ty), *) => {
+        $(
+            impl Add for Checked<$t> {
+                type Output = Self;
+                fn add(self, rhs: Self) -> Self::Output {
+                    Self {
+                        value: self.value.zip(rhs.value).and_then(|(a, b)| a.checked_add(b)),
+                    }
+                }
+            }
+            impl Sub for Checked<$t> {
+                type Output = Self;
+                fn sub(self, rhs: Self) -> Self::Output {
+                    Self {
+                        value: self.value.zip(rhs.value).and_then(|(a, b)| a.checked_sub(b)),
+                    }
+                }
+            }
+            impl Mul for Checked<$t> {
+                type Output = Self;
+                fn mul(self, rhs: Self) -> Self::Output {
+                    Self {
+                        value: self.value.zip(rhs.value).and_then(|(a, b)| a.checked_mul(b)),
+                    }
+                }
+            }
+            impl Neg for Checked<$t> {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self {
+                        value: self.value.and_then(|a| a.checked_neg()),
+                    }
+                }
+            }
+            impl Zero for Checked<$t> {
+                fn zero() -> Self {
+                    Self::new(0)
+                }
+                fn is_zero(&self) -> bool {
+                    self.value == Some(0)
+                }
+            }
+            impl One for Checked<$t> {
+                fn one() -> Self {
+                    Self::new(1)
+                }
+                fn is_one(&self) -> bool {
+                    self.value == Some(1)
+                }
+            }
+            impl Abelian for Checked<$t> {}
+            impl Ring for Checked<$t> {}
+        )*
+    };
+}
+impl_checked!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::Checked;
+    use algebra::{One, Zero};
+
+    #[test]
+    fn matches_wide_arithmetic() {
+        let fits_i8 = |x: i32| i8::try_from(x).ok();
+        let fits_u8 = |x: i32| u8::try_from(x).ok();
+        for a in i8::MIN..=i8::MAX {
+            assert_eq!((-Checked::new(a)).value(), fits_i8(-(a as i32)));
+            for b in i8::MIN..=i8::MAX {
+                let (x, y) = (Checked::new(a), Checked::new(b));
+                let (a, b) = (a as i32, b as i32);
+                assert_eq!((x + y).value(), fits_i8(a + b));
+                assert_eq!((x - y).value(), fits_i8(a - b));
+                assert_eq!((x * y).value(), fits_i8(a * b));
+            }
+        }
+        for a in 0..=u8::MAX {
+            assert_eq!((-Checked::new(a)).value(), fits_u8(-(a as i32)));
+            for b in 0..=u8::MAX {
+                let (x, y) = (Checked::new(a), Checked::new(b));
+                let (a, b) = (a as i32, b as i32);
+                assert_eq!((x + y).value(), fits_u8(a + b));
+                assert_eq!((x - y).value(), fits_u8(a - b));
+                assert_eq!((x * y).value(), fits_u8(a * b));
+            }
+        }
+    }
+
+    #[test]
+    fn overflow_is_sticky() {
+        let overflow = Checked::new(u64::MAX) + Checked::one();
+        assert!(overflow.is_overflowed());
+        assert_eq!(format!("{:?}", overflow), "overflow");
+        assert!((overflow * Checked::zero()).is_overflowed());
+        assert!((overflow - overflow).is_overflowed());
+        assert!((Checked::new(0u64) + overflow).is_overflowed());
+        assert!(!overflow.is_zero() && !overflow.is_one());
+        assert_eq!(format!("{:?}", Checked::new(5i32)), "5");
+    }
+}
@@ -1,3 +1,5 @@
+use super::factorization::Factorization;
+
 pub fn divisors(n: u64) -> Vec<u64> {
     let mut prefix = vec![];
     let mut suffix = vec![];
@@ -12,3 +14,7 @@ pub fn divisors(n: u64) -> Vec<u64> {
     prefix.extend(suffix.iter().rev());
     prefix
 }
+
+pub fn divisors_fast(n: u64) -> Vec<u64> {
+    Factorization::new(n).divisors().collect()
+}
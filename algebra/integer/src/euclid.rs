@@ -0,0 +1,108 @@
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b > 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+pub fn ext_gcd(a: u64, b: u64) -> (u64, i128, i128) {
+    let (mut a, mut b) = (a as i128, b as i128);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while b > 0 {
+        let q = a / b;
+        (a, b) = (b, a - q * b);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    (a as u64, x0, y0)
+}
+
+pub fn inv_mod(a: u64, m: u64) -> Option<u64> {
+    let (g, x, _) = ext_gcd(a % m, m);
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(m as i128) as u64)
+}
+
+pub fn crt(r: &[u64], m: &[u64]) -> Option<(u64, u64)> {
+    assert_eq!(r.len(), m.len());
+    let (mut r0, mut m0) = (0u128, 1u128);
+    for (&r1, &m1) in r.iter().zip(m) {
+        let (r1, m1) = (r1 as u128 % m1 as u128, m1 as u128);
+        let g = gcd(m0 as u64, m1 as u64) as u128;
+        let d = (r1 + m1 - r0 % m1) % m1;
+        if d % g != 0 {
+            return None;
+        }
+        let m2 = m1 / g;
+        let k = d / g * inv_mod((m0 / g % m2) as u64, m2 as u64).unwrap() as u128 % m2;
+        r0 += m0 * k;
+        m0 *= m2;
+        if m0 > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some((r0 as u64, m0 as u64))
+}
+
+pub fn garner(r: &[u64], m: &[u64], modulus: u64) -> u64 {
+    assert_eq!(r.len(), m.len());
+    let k = r.len();
+    let mut moduli = m.iter().map(|&m| m as u128).collect::<Vec<_>>();
+    moduli.push(modulus as u128);
+    let mut coefficients = moduli.iter().map(|&m| 1 % m).collect::<Vec<_>>();
+    let mut constants = vec![0u128; k + 1];
+    for i in 0..k {
+        let m = moduli[i];
+        let inv = inv_mod(coefficients[i] as u64, m as u64).unwrap() as u128;
+        let v = (r[i] as u128 % m + m - constants[i]) % m * inv % m;
+        for j in i + 1..=k {
+            constants[j] = (constants[j] + v * coefficients[j]) % moduli[j];
+            coefficients[j] = coefficients[j] * (m % moduli[j]) % moduli[j];
+        }
+    }
+    constants[k] as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::crt;
+
+    #[test]
+    fn crt_matches_brute_force() {
+        for m1 in 1..12u64 {
+            for m2 in 1..12u64 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let x = (0..m1 * m2).find(|x| x % m1 == r1 && x % m2 == r2);
+                        let res = crt(&[r1, r2], &[m1, m2]);
+                        match x {
+                            Some(x) => {
+                                let (y, l) = res.unwrap();
+                                assert_eq!(y, x);
+                                assert_eq!(l, m1 * m2 / super::gcd(m1, m2));
+                            }
+                            None => assert_eq!(res, None),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn crt_overflow_returns_none() {
+        let m = [u64::MAX, u64::MAX - 1];
+        assert_eq!(crt(&[1, 1], &m), None);
+        assert_eq!(crt(&[5, 5], &[u64::MAX, u64::MAX]), Some((5, u64::MAX)));
+    }
+}
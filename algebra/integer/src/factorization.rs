@@ -0,0 +1,161 @@
+use super::prime::factorize;
+
+use std::ops::{Mul, MulAssign};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Factorization {
+    prime_powers: Vec<(u64, u32)>,
+}
+
+impl Factorization {
+    /// # Panics
+    ///
+    /// Panics if `n == 0`.
+    pub fn new(n: u64) -> Self {
+        Self {
+            prime_powers: factorize(n),
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.iter().map(|(p, e)| p.pow(e)).product()
+    }
+
+    pub fn prime_powers(&self) -> &[(u64, u32)] {
+        &self.prime_powers
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u64, u32)> + '_ {
+        self.prime_powers.iter().copied()
+    }
+
+    pub fn divisor_count(&self) -> u64 {
+        self.iter().map(|(_, e)| e as u64 + 1).product()
+    }
+
+    pub fn divisor_sum(&self) -> u128 {
+        self.iter()
+            .map(|(p, e)| (0..=e).map(|i| (p as u128).pow(i)).sum::<u128>())
+            .product()
+    }
+
+    pub fn phi(&self) -> u64 {
+        self.iter().map(|(p, e)| p.pow(e - 1) * (p - 1)).product()
+    }
+
+    pub fn is_square_free(&self) -> bool {
+        self.iter().all(|(_, e)| e == 1)
+    }
+
+    pub fn divisors(&self) -> impl Iterator<Item = u64> {
+        let mut res = vec![1];
+        for (p, e) in self.iter() {
+            let len = res.len();
+            let mut pow = 1;
+            for _ in 0..e {
+                pow *= p;
+                for i in 0..len {
+                    res.push(res[i] * pow);
+                }
+            }
+        }
+        res.sort_unstable();
+        res.into_iter()
+    }
+
+    pub fn gcd(&self, other: &Self) -> Self {
+        self.merge(other, u32::min)
+    }
+
+    pub fn lcm(&self, other: &Self) -> Self {
+        self.merge(other, u32::max)
+    }
+
+    fn merge(&self, other: &Self, f: impl Fn(u32, u32) -> u32) -> Self {
+        let (a, b) = (&self.prime_powers, &other.prime_powers);
+        let (mut i, mut j) = (0, 0);
+        let mut prime_powers = vec![];
+        while i < a.len() || j < b.len() {
+            let (p, e) = if j == b.len() || (i < a.len() && a[i].0 < b[j].0) {
+                i += 1;
+                (a[i - 1].0, f(a[i - 1].1, 0))
+            } else if i == a.len() || b[j].0 < a[i].0 {
+                j += 1;
+                (b[j - 1].0, f(0, b[j - 1].1))
+            } else {
+                i += 1;
+                j += 1;
+                (a[i - 1].0, f(a[i - 1].1, b[j - 1].1))
+            };
+            if e > 0 {
+                prime_powers.push((p, e));
+            }
+        }
+        Self { prime_powers }
+    }
+}
+
+impl FromIterator<(u64, u32)> for Factorization {
+    fn from_iter<I: IntoIterator<Item = (u64, u32)>>(iter: I) -> Self {
+        let mut primes = iter.into_iter().filter(|&(_, e)| e > 0).collect::<Vec<_>>();
+        primes.sort_unstable();
+        let mut prime_powers: Vec<(u64, u32)> = vec![];
+        for (p, e) in primes {
+            match prime_powers.last_mut() {
+                Some((q, f)) if *q == p => *f += e,
+                _ => prime_powers.push((p, e)),
+            }
+        }
+        Self { prime_powers }
+    }
+}
+
+impl MulAssign<&Factorization> for Factorization {
+    fn mul_assign(&mut self, rhs: &Factorization) {
+        *self = self.merge(rhs, |a, b| a + b);
+    }
+}
+impl Mul<&Factorization> for &Factorization {
+    type Output = Factorization;
+    fn mul(self, rhs: &Factorization) -> Self::Output {
+        self.merge(rhs, |a, b| a + b)
+    }
+}
+impl Mul for Factorization {
+    type Output = Factorization;
+    fn mul(mut self, rhs: Factorization) -> Self::Output {
+        self *= &rhs;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Factorization;
+    use crate::{divisors::divisors_fast, euclid::gcd, prime::euler_phi};
+
+    #[test]
+    fn divisors_and_phi_match_brute_force() {
+        for n in 1..2000u64 {
+            let divisors = (1..=n).filter(|d| n.is_multiple_of(*d)).collect::<Vec<_>>();
+            let phi = (1..=n).filter(|&k| gcd(k, n) == 1).count();
+            assert_eq!(divisors_fast(n), divisors);
+            assert_eq!(euler_phi(n), phi as u64);
+        }
+    }
+
+    #[test]
+    fn one_has_no_prime_powers() {
+        let one = Factorization::new(1);
+        assert!(one.prime_powers().is_empty());
+        assert_eq!(one.value(), 1);
+        assert_eq!(one.divisors().collect::<Vec<_>>(), [1]);
+        assert_eq!(one.phi(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot factorize 0")]
+    fn zero_panics() {
+        Factorization::new(0);
+    }
+}
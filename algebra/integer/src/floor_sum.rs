@@ -1,13 +1,25 @@
-pub fn floor_sum(mut n: i64, mut m: i64, mut a: i64, mut b: i64) -> i64 {
+use algebra::{
+    monoid::{Monoid, Semigroup},
+    Ring,
+};
+
+use std::marker::PhantomData;
+
+pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i64 {
+    floor_sum_i128(n as i128, m as i128, a as i128, b as i128) as i64
+}
+
+fn floor_sum_i128(mut n: i128, mut m: i128, mut a: i128, mut b: i128) -> i128 {
+    assert!(n >= 0 && m > 0);
     let mut res = 0;
     while m > 0 {
-        if a >= m {
-            res += a / m * n * (n - 1) / 2;
-            a %= m;
+        if !(0..m).contains(&a) {
+            res += a.div_euclid(m) * n * (n - 1) / 2;
+            a = a.rem_euclid(m);
         }
-        if b >= m {
-            res += b / m * n;
-            b %= m;
+        if !(0..m).contains(&b) {
+            res += b.div_euclid(m) * n;
+            b = b.rem_euclid(m);
         }
         let k = a * n + b;
         if k < m {
@@ -19,3 +31,236 @@ pub fn floor_sum(mut n: i64, mut m: i64, mut a: i64, mut b: i64) -> i64 {
     }
     res
 }
+
+pub fn min_of_mod_of_linear(n: i64, m: i64, a: i64, b: i64) -> i64 {
+    assert!(n > 0 && m > 0);
+    let (n, m, a, b) = (n as i128, m as i128, a as i128, b as i128);
+    let total = floor_sum_i128(n, m, a, b);
+    let (mut ng, mut ok) = (-1, m - 1);
+    while ok - ng > 1 {
+        let mid = (ng + ok) / 2;
+        if total > floor_sum_i128(n, m, a, b - mid - 1) {
+            ok = mid;
+        } else {
+            ng = mid;
+        }
+    }
+    ok as i64
+}
+
+fn pow<M: Monoid>(x: &M::Value, mut exp: u64) -> M::Value {
+    let mut res = M::e();
+    let mut base = x.clone();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = M::op(&res, &base);
+        }
+        base = M::op(&base, &base);
+        exp >>= 1;
+    }
+    res
+}
+
+pub fn universal_euclid<M: Monoid>(
+    n: u64,
+    m: u64,
+    a: u64,
+    b: u64,
+    u: &M::Value,
+    r: &M::Value,
+) -> M::Value {
+    if n == 0 {
+        return M::e();
+    }
+    let res = M::op(&pow::<M>(u, b / m), r);
+    M::op(&res, &universal_euclid_rec::<M>(a, m, b % m, n - 1, u, r))
+}
+
+fn universal_euclid_rec<M: Monoid>(
+    p: u64,
+    q: u64,
+    r: u64,
+    l: u64,
+    u: &M::Value,
+    v: &M::Value,
+) -> M::Value {
+    if l == 0 {
+        return M::e();
+    }
+    if p >= q {
+        let v = M::op(&pow::<M>(u, p / q), v);
+        return universal_euclid_rec::<M>(p % q, q, r, l, u, &v);
+    }
+    let m = ((p as u128 * l as u128 + r as u128) / q as u128) as u64;
+    if m == 0 {
+        return pow::<M>(v, l);
+    }
+    let cnt = l - ((q as u128 * m as u128 - r as u128 - 1) / p as u128) as u64;
+    let res = M::op(&pow::<M>(v, (q - r - 1) / p), u);
+    let res = M::op(
+        &res,
+        &universal_euclid_rec::<M>(q, p, (q - r - 1) % p, m - 1, v, u),
+    );
+    M::op(&res, &pow::<M>(v, cnt))
+}
+
+struct FloorPowerSum<T, const K: usize, const L: usize> {
+    phantom: PhantomData<T>,
+}
+
+#[derive(Clone)]
+struct FloorPowerSumValue<T> {
+    x: T,
+    y: T,
+    sum: Vec<Vec<T>>,
+}
+
+impl<T: Ring + Copy, const K: usize, const L: usize> Semigroup for FloorPowerSum<T, K, L> {
+    type Value = FloorPowerSumValue<T>;
+    fn op(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value {
+        let d = K.max(L);
+        let mut binom = vec![vec![T::zero(); d + 1]; d + 1];
+        for i in 0..=d {
+            binom[i][0] = T::one();
+            for j in 1..=i {
+                binom[i][j] = binom[i - 1][j - 1] + binom[i - 1][j];
+            }
+        }
+        let mut x_pow = vec![T::one(); K + 1];
+        for i in 1..=K {
+            x_pow[i] = x_pow[i - 1] * lhs.x;
+        }
+        let mut y_pow = vec![T::one(); L + 1];
+        for j in 1..=L {
+            y_pow[j] = y_pow[j - 1] * lhs.y;
+        }
+        let mut sum = lhs.sum.clone();
+        for i in 0..=K {
+            for j in 0..=L {
+                for s in 0..=i {
+                    for t in 0..=j {
+                        sum[i][j] = sum[i][j]
+                            + binom[i][s]
+                                * binom[j][t]
+                                * x_pow[i - s]
+                                * y_pow[j - t]
+                                * rhs.sum[s][t];
+                    }
+                }
+            }
+        }
+        FloorPowerSumValue {
+            x: lhs.x + rhs.x,
+            y: lhs.y + rhs.y,
+            sum,
+        }
+    }
+}
+impl<T: Ring + Copy, const K: usize, const L: usize> Monoid for FloorPowerSum<T, K, L> {
+    fn e() -> Self::Value {
+        FloorPowerSumValue {
+            x: T::zero(),
+            y: T::zero(),
+            sum: vec![vec![T::zero(); L + 1]; K + 1],
+        }
+    }
+}
+
+pub fn floor_power_sum<T: Ring + Copy, const K: usize, const L: usize>(
+    n: u64,
+    m: u64,
+    a: u64,
+    b: u64,
+) -> Vec<Vec<T>> {
+    let u = FloorPowerSumValue {
+        x: T::zero(),
+        y: T::one(),
+        sum: vec![vec![T::zero(); L + 1]; K + 1],
+    };
+    let mut r = FloorPowerSumValue {
+        x: T::one(),
+        y: T::zero(),
+        sum: vec![vec![T::zero(); L + 1]; K + 1],
+    };
+    r.sum[0][0] = T::one();
+    universal_euclid::<FloorPowerSum<T, K, L>>(n, m, a, b, &u, &r).sum
+}
+
+struct FloorGeometricSum<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: Ring + Copy> Semigroup for FloorGeometricSum<T> {
+    type Value = (T, T, T);
+    fn op(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value {
+        (lhs.0 * rhs.0, lhs.1 * rhs.1, lhs.2 + lhs.0 * rhs.2 * lhs.1)
+    }
+}
+impl<T: Ring + Copy> Monoid for FloorGeometricSum<T> {
+    fn e() -> Self::Value {
+        (T::one(), T::one(), T::zero())
+    }
+}
+
+pub fn floor_geometric_sum<T: Ring + Copy>(n: u64, m: u64, a: u64, b: u64, x: T, y: T) -> T {
+    let u = (T::one(), y, T::zero());
+    let r = (x, T::one(), T::one());
+    universal_euclid::<FloorGeometricSum<T>>(n, m, a, b, &u, &r).2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{floor_geometric_sum, floor_power_sum, floor_sum, min_of_mod_of_linear};
+
+    #[test]
+    fn floor_sum_matches_brute_force() {
+        for n in 0..12i64 {
+            for m in 1..12 {
+                for a in -20..20 {
+                    for b in -20..20 {
+                        let expected = (0..n).map(|i| (a * i + b).div_euclid(m)).sum::<i64>();
+                        assert_eq!(floor_sum(n, m, a, b), expected, "{n} {m} {a} {b}");
+                        if n > 0 {
+                            let expected = (0..n).map(|i| (a * i + b).rem_euclid(m)).min();
+                            assert_eq!(Some(min_of_mod_of_linear(n, m, a, b)), expected);
+                        }
+                    }
+                }
+            }
+        }
+        let n = 1_000_000_000;
+        assert_eq!(floor_sum(n, 1, 1, 0), n * (n - 1) / 2);
+    }
+
+    #[test]
+    fn floor_power_sum_matches_brute_force() {
+        for n in 0..10u64 {
+            for m in 1..8u64 {
+                for a in 0..10u64 {
+                    for b in 0..10u64 {
+                        let sum = floor_power_sum::<i128, 2, 3>(n, m, a, b);
+                        for (i, row) in sum.iter().enumerate() {
+                            for (j, &s) in row.iter().enumerate() {
+                                let expected = (0..n)
+                                    .map(|x| {
+                                        (x as i128).pow(i as u32)
+                                            * (((a * x + b) / m) as i128).pow(j as u32)
+                                    })
+                                    .sum::<i128>();
+                                assert_eq!(s, expected, "{n} {m} {a} {b} {i} {j}");
+                            }
+                        }
+                        if a >= 5 || b >= 5 {
+                            continue;
+                        }
+                        // i128 keeps powers of -2 exact while the exponent stays small
+                        let expected = (0..n)
+                            .map(|x| 3i128.pow(x as u32) * (-2i128).pow(((a * x + b) / m) as u32))
+                            .sum::<i128>();
+                        assert_eq!(floor_geometric_sum(n, m, a, b, 3i128, -2i128), expected);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,12 @@
+pub mod binomial;
 pub mod change_min_max;
+pub mod checked;
 pub mod divisors;
+pub mod euclid;
+pub mod factorization;
 pub mod floor_sum;
 pub mod meru_prastaar;
+pub mod multiplicative;
 pub mod prime;
 pub mod quotients;
+pub mod residue;
@@ -1,18 +1,21 @@
-pub struct MeruPrastaar {
+use algebra::{One, Zero};
+
+pub struct MeruPrastaar<T> {
     n: usize,
-    values: Box<[usize]>,
+    values: Box<[T]>,
 }
 
-impl MeruPrastaar {
+impl<T: Zero + One + Copy> MeruPrastaar<T> {
     pub fn new(n: usize) -> Self {
-        let mut values = vec![0usize; (n + 1) * (n + 1)];
-        values[0] = 1;
+        let mut values = Vec::with_capacity((n + 1) * (n + 2) / 2);
+        values.push(T::one());
         for i in 1..=n {
-            values[i * (n + 1)] = 1;
-            for j in 1..=i {
-                values[i * (n + 1) + j] =
-                    values[(i - 1) * (n + 1) + j - 1] + values[(i - 1) * (n + 1) + j];
+            let prev = values.len() - i;
+            values.push(T::one());
+            for j in 1..i {
+                values.push(values[prev + j - 1] + values[prev + j]);
             }
+            values.push(T::one());
         }
         Self {
             n,
@@ -20,7 +23,64 @@ impl MeruPrastaar {
         }
     }
 
-    pub fn binom(&self, n: usize, r: usize) -> usize {
-        self.values[n * (self.n + 1) + r]
+    pub fn row(n: usize) -> Vec<T> {
+        let mut row = vec![T::zero(); n + 1];
+        row[0] = T::one();
+        for i in 1..=n {
+            for j in (1..i).rev() {
+                row[j] = row[j] + row[j - 1];
+            }
+            row[i] = T::one();
+        }
+        row
+    }
+
+    pub fn binom(&self, n: usize, r: usize) -> T {
+        assert!(n <= self.n);
+        if r > n {
+            return T::zero();
+        }
+        self.values[n * (n + 1) / 2 + r]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MeruPrastaar;
+    use crate::checked::Checked;
+
+    #[test]
+    fn matches_multiplicative_formula() {
+        const N: usize = 120;
+        let table = MeruPrastaar::<u128>::new(N);
+        for n in 0..=N {
+            let row = MeruPrastaar::<u128>::row(n);
+            assert_eq!(row.len(), n + 1);
+            let mut binom = 1u128;
+            for (r, &value) in row.iter().enumerate() {
+                assert_eq!(table.binom(n, r), binom);
+                assert_eq!(value, binom);
+                binom = binom * (n - r) as u128 / (r + 1) as u128;
+            }
+            assert_eq!(table.binom(n, n + 1), 0);
+        }
+    }
+
+    #[test]
+    fn checked_overflow_boundary() {
+        let table = MeruPrastaar::<Checked<u64>>::new(70);
+        assert!((0..=67).all(|r| !table.binom(67, r).is_overflowed()));
+        assert_eq!(
+            table.binom(68, 30).value(),
+            Some(17_876_288_714_431_443_296)
+        );
+        assert!((31..=37).all(|r| table.binom(68, r).is_overflowed()));
+        assert!(table.binom(70, 35).is_overflowed());
+    }
+
+    #[test]
+    #[should_panic]
+    fn binom_beyond_table() {
+        MeruPrastaar::<u64>::new(10).binom(11, 0);
     }
 }
@@ -0,0 +1,198 @@
+use super::quotients::quotients;
+
+use algebra::Ring;
+
+fn isqrt(n: u64) -> u64 {
+    let mut r = (n as f64).sqrt() as u64;
+    while r * r > n {
+        r -= 1;
+    }
+    while (r + 1) * (r + 1) <= n {
+        r += 1;
+    }
+    r
+}
+
+fn index(n: u64, r: u64, len: usize, v: u64) -> usize {
+    if v <= r {
+        v as usize - 1
+    } else {
+        len - (n / v) as usize
+    }
+}
+
+fn small_primes(r: u64) -> Vec<u64> {
+    let mut is_prime = vec![true; r as usize + 1];
+    let mut res = vec![];
+    for p in 2..=r as usize {
+        if is_prime[p] {
+            res.push(p as u64);
+            for q in (p * p..=r as usize).step_by(p) {
+                is_prime[q] = false;
+            }
+        }
+    }
+    res
+}
+
+pub fn prime_prefix_sums<T: Ring + Copy>(
+    n: u64,
+    f: impl Fn(u64) -> T,
+    prefix: impl Fn(u64) -> T,
+) -> Vec<T> {
+    let q = quotients(n);
+    let r = isqrt(n);
+    let len = q.len();
+    let mut res = q.iter().map(|&v| prefix(v) - f(1)).collect::<Vec<_>>();
+    for p in small_primes(r) {
+        let fp = f(p);
+        let base = res[p as usize - 2];
+        for i in (0..len).rev() {
+            let v = q[i];
+            if v < p * p {
+                break;
+            }
+            res[i] = res[i] - fp * (res[index(n, r, len, v / p)] - base);
+        }
+    }
+    res
+}
+
+pub fn prime_count(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
+    }
+    *prime_prefix_sums(n, |_| 1i64, |v| v as i64).last().unwrap() as u64
+}
+
+pub fn prime_sum(n: u64) -> u128 {
+    if n < 2 {
+        return 0;
+    }
+    let sums = prime_prefix_sums(n, |p| p as i128, |v| v as i128 * (v as i128 + 1) / 2);
+    *sums.last().unwrap() as u128
+}
+
+pub fn min_25<T: Ring + Copy>(n: u64, prime_sums: &[T], f: impl Fn(u64, u32) -> T) -> T {
+    if n == 0 {
+        return T::zero();
+    }
+    let r = isqrt(n);
+    let primes = small_primes(r);
+    assert_eq!(prime_sums.len(), quotients(n).len());
+    T::one() + min_25_rec((n, r), &primes, prime_sums, &f, n, 0)
+}
+
+fn min_25_rec<T: Ring + Copy>(
+    (n, r): (u64, u64),
+    primes: &[u64],
+    prime_sums: &[T],
+    f: &impl Fn(u64, u32) -> T,
+    v: u64,
+    j: usize,
+) -> T {
+    let index = |v: u64| index(n, r, prime_sums.len(), v);
+    let mut res = prime_sums[index(v)];
+    if j > 0 {
+        res = res - prime_sums[index(primes[j - 1])];
+    }
+    for (k, &p) in primes.iter().enumerate().skip(j) {
+        if p * p > v {
+            break;
+        }
+        let mut pe = p;
+        let mut e = 1;
+        while pe <= v / p {
+            res = res
+                + f(p, e) * min_25_rec((n, r), primes, prime_sums, f, v / pe, k + 1)
+                + f(p, e + 1);
+            pe *= p;
+            e += 1;
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{min_25, prime_count, prime_prefix_sums, prime_sum};
+    use crate::factorization::Factorization;
+
+    #[test]
+    fn prime_count_and_sum_match_sieve() {
+        const N: usize = 5000;
+        let mut is_prime = vec![true; N + 1];
+        is_prime[0] = false;
+        is_prime[1] = false;
+        for i in 2..=N {
+            if is_prime[i] {
+                for j in (i * i..=N).step_by(i) {
+                    is_prime[j] = false;
+                }
+            }
+        }
+        let (mut count, mut sum) = (0, 0);
+        for (n, &is_prime) in is_prime.iter().enumerate() {
+            if is_prime {
+                count += 1;
+                sum += n as u128;
+            }
+            assert_eq!(prime_count(n as u64), count);
+            assert_eq!(prime_sum(n as u64), sum);
+        }
+        assert_eq!(prime_count(1_000_000), 78_498);
+        assert_eq!(prime_sum(1_000_000), 37_550_402_023);
+        assert_eq!(prime_count(10_000_000_000), 455_052_511);
+    }
+
+    fn check_min_25(
+        n: u64,
+        f: impl Fn(u64, u32) -> i64,
+        g: impl Fn(&[i64], &[i64]) -> Vec<i64>,
+    ) -> i64 {
+        let count = prime_prefix_sums(n, |_| 1i64, |v| v as i64);
+        let sum = prime_prefix_sums(n, |p| p as i64, |v| (v * (v + 1) / 2) as i64);
+        min_25(n, &g(&count, &sum), f)
+    }
+
+    #[test]
+    fn min_25_matches_brute_force() {
+        let (mut phi, mut sigma0, mut mobius) = (0, 0, 0);
+        for n in 1..=3000u64 {
+            let factorization = Factorization::new(n);
+            phi += factorization.phi() as i64;
+            sigma0 += factorization.divisor_count() as i64;
+            if factorization.iter().all(|(_, e)| e == 1) {
+                mobius += if factorization.prime_powers().len().is_multiple_of(2) {
+                    1
+                } else {
+                    -1
+                };
+            }
+            let zip = |a: &[i64], b: &[i64], h: fn(i64, i64) -> i64| {
+                a.iter().zip(b).map(|(&a, &b)| h(a, b)).collect::<Vec<_>>()
+            };
+            assert_eq!(
+                check_min_25(
+                    n,
+                    |p, e| ((p - 1) * p.pow(e - 1)) as i64,
+                    |c, s| zip(c, s, |c, s| s - c)
+                ),
+                phi
+            );
+            assert_eq!(
+                check_min_25(n, |_, e| e as i64 + 1, |c, s| zip(c, s, |c, _| 2 * c)),
+                sigma0
+            );
+            assert_eq!(
+                check_min_25(
+                    n,
+                    |_, e| if e == 1 { -1 } else { 0 },
+                    |c, s| zip(c, s, |c, _| -c)
+                ),
+                mobius
+            );
+        }
+        assert_eq!(min_25(0, &[], |_, _| 1i64), 0);
+    }
+}
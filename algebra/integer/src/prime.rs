@@ -1,4 +1,10 @@
-pub fn is_prime(n: u64) -> bool {
+use super::{
+    euclid::{gcd, lcm},
+    factorization::Factorization,
+    residue::mul_mod,
+};
+
+pub const fn is_prime(n: u64) -> bool {
     if n == 0 || n == 1 {
         return false;
     }
@@ -9,41 +15,142 @@ pub fn is_prime(n: u64) -> bool {
         return false;
     }
 
+    let a: &[u64] = if n < 4_759_123_141 {
+        &[2, 7, 61]
+    } else {
+        &[2, 325, 9_375, 28_178, 450_775, 9_780_504, 1_795_265_022]
+    };
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] < n && !miller_rabin(n, a[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+pub(crate) const fn miller_rabin(n: u64, a: u64) -> bool {
     let r = (n - 1).trailing_zeros();
-    let d = (n - 1) >> r;
-
-    let miller_rabin = |a: u64| -> bool {
-        let n = n as u128;
-        let mut base = a as u128;
-        let mut pow = 1;
-        let mut d = d;
-        while d > 0 {
-            if d & 1 == 1 {
-                pow *= base;
-                pow %= n;
-            }
-            base *= base;
-            base %= n;
-            d >>= 1;
+    let n = n as u128;
+    let mut d = (n - 1) >> r;
+    let mut base = a as u128 % n;
+    let mut pow = 1;
+    while d > 0 {
+        if d & 1 == 1 {
+            pow *= base;
+            pow %= n;
         }
-        if pow == 1 || pow == n - 1 {
+        base *= base;
+        base %= n;
+        d >>= 1;
+    }
+    if pow == 1 || pow == n - 1 {
+        return true;
+    }
+    let mut i = 1;
+    while i < r {
+        pow *= pow;
+        pow %= n;
+        if pow == n - 1 {
             return true;
         }
-        for _ in 1..r {
-            pow *= pow;
-            pow %= n;
-            if pow == n - 1 {
-                return true;
+        i += 1;
+    }
+    false
+}
+
+/// Returns the prime factorization of `n` as `(p, e)` pairs sorted by `p`;
+/// `factorize(1)` is empty.
+///
+/// # Panics
+///
+/// Panics if `n == 0`.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    assert!(n > 0, "cannot factorize 0");
+    let mut primes = vec![];
+    let mut n = n;
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    let mut stack = vec![n];
+    while let Some(n) = stack.pop() {
+        if n == 1 {
+            continue;
+        }
+        if is_prime(n) {
+            primes.push(n);
+            continue;
+        }
+        let d = pollard_rho(n);
+        stack.push(d);
+        stack.push(n / d);
+    }
+    primes.sort_unstable();
+    let mut res: Vec<(u64, u32)> = vec![];
+    for p in primes {
+        match res.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => res.push((p, 1)),
+        }
+    }
+    res
+}
+
+fn pollard_rho(n: u64) -> u64 {
+    let m = 1 << ((64 - n.leading_zeros()) / 8);
+    for c in 1.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c) % n as u128) as u64;
+        let (mut x, mut y, mut ys) = (0, 2, 0);
+        let (mut g, mut q, mut r) = (1, 1, 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
             }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..m.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += m;
+            }
+            r *= 2;
         }
-        false
-    };
+        if g == n {
+            g = 1;
+            while g == 1 {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
 
-    let a = if n < 4_759_123_141 {
-        vec![2, 7, 61]
-    } else {
-        vec![2, 325, 9_375, 28_178, 450_775, 9_780_504, 1_795_265_022]
-    };
+pub fn euler_phi(n: u64) -> u64 {
+    Factorization::new(n).phi()
+}
 
-    a.iter().filter(|&&a| a < n).all(|&a| miller_rabin(a))
+pub fn carmichael_lambda(n: u64) -> u64 {
+    factorize(n)
+        .iter()
+        .map(|&(p, e)| {
+            if p == 2 && e >= 3 {
+                1 << (e - 2)
+            } else {
+                p.pow(e - 1) * (p - 1)
+            }
+        })
+        .fold(1, lcm)
 }
@@ -0,0 +1,262 @@
+use super::{
+    euclid::{gcd, inv_mod},
+    prime::factorize,
+};
+
+use std::collections::HashMap;
+
+pub fn pow_mod(a: u64, mut exp: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    let mut base = a % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mul_mod(res, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    res
+}
+
+pub fn sqrt_mod(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if a < 2 || p == 2 {
+        return Some(a);
+    }
+    if pow_mod(a, (p - 1) / 2, p) != 1 {
+        return None;
+    }
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let z = (2..)
+        .find(|&z| pow_mod(z, (p - 1) / 2, p) == p - 1)
+        .unwrap();
+    let mut m = s;
+    let mut c = pow_mod(z, q, p);
+    let mut t = pow_mod(a, q, p);
+    let mut r = pow_mod(a, q.div_ceil(2), p);
+    while t != 1 {
+        let mut i = 0;
+        let mut tt = t;
+        while tt != 1 {
+            tt = mul_mod(tt, tt, p);
+            i += 1;
+        }
+        let b = pow_mod(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        r = mul_mod(r, b, p);
+    }
+    Some(r)
+}
+
+pub fn kth_root_mod(a: u64, k: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if k == 0 {
+        return if a == 1 % p { Some(1 % p) } else { None };
+    }
+    if a < 2 || p == 2 {
+        return Some(a);
+    }
+    let m = p - 1;
+    let g = gcd(k, m);
+    if pow_mod(a, m / g, p) != 1 {
+        return None;
+    }
+    let mut y = a;
+    for (q, _) in factorize(g) {
+        let mut h = g;
+        while h.is_multiple_of(q) {
+            y = prime_root_mod(y, q, p);
+            h /= q;
+        }
+    }
+    Some(pow_mod(y, inv_mod(k / g % (m / g), m / g).unwrap(), p))
+}
+
+fn prime_root_mod(a: u64, q: u64, p: u64) -> u64 {
+    let m = p - 1;
+    let mut s = m;
+    let mut t = 0;
+    while s.is_multiple_of(q) {
+        s /= q;
+        t += 1;
+    }
+    let mut x = pow_mod(a, inv_mod(q % s, s).unwrap(), p);
+    let mut b = mul_mod(pow_mod(x, q, p), inv_mod(a, p).unwrap(), p);
+    let c = (2..).find(|&c| pow_mod(c, m / q, p) != 1).unwrap();
+    let z = pow_mod(c, s, p);
+    let z_inv = inv_mod(z, p).unwrap();
+    let zq = pow_mod(z, q.pow(t - 1), p);
+
+    let n = (q as f64).sqrt() as u64 + 1;
+    let mut baby = HashMap::new();
+    let mut cur = 1;
+    for j in 0..n {
+        baby.entry(cur).or_insert(j);
+        cur = mul_mod(cur, zq, p);
+    }
+    let giant = inv_mod(cur, p).unwrap();
+
+    while b != 1 {
+        let mut i = 0;
+        let mut bb = b;
+        while bb != 1 {
+            bb = pow_mod(bb, q, p);
+            i += 1;
+        }
+        let mut w = pow_mod(b, q.pow(i - 1), p);
+        let mut j = 0;
+        for l in 0..n {
+            if let Some(&r) = baby.get(&w) {
+                j = l * n + r;
+                break;
+            }
+            w = mul_mod(w, giant, p);
+        }
+        let y = pow_mod(z_inv, j * q.pow(t - i - 1), p);
+        x = mul_mod(x, y, p);
+        b = mul_mod(b, pow_mod(y, q, p), p);
+    }
+    x
+}
+
+pub fn discrete_log(a: u64, b: u64, m: u64) -> Option<u64> {
+    let mut a = a % m;
+    let mut b = b % m;
+    let mut m = m;
+    let mut k = 1 % m;
+    let mut add = 0;
+    loop {
+        let g = gcd(a, m);
+        if g == 1 {
+            break;
+        }
+        if b == k {
+            return Some(add);
+        }
+        if !b.is_multiple_of(g) {
+            return None;
+        }
+        b /= g;
+        m /= g;
+        add += 1;
+        k = mul_mod(k, a / g, m);
+        a %= m;
+    }
+    let n = (m as f64).sqrt() as u64 + 1;
+    let mut baby = HashMap::new();
+    let mut cur = b;
+    for q in 0..=n {
+        baby.insert(cur, q);
+        cur = mul_mod(cur, a, m);
+    }
+    let an = pow_mod(a, n, m);
+    let mut cur = k;
+    for p in 1..=n {
+        cur = mul_mod(cur, an, m);
+        if let Some(&q) = baby.get(&cur) {
+            return Some(n * p - q + add);
+        }
+    }
+    None
+}
+
+pub fn multiplicative_order(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if a == 0 {
+        return None;
+    }
+    let mut res = p - 1;
+    for (q, _) in factorize(p - 1) {
+        while res.is_multiple_of(q) && pow_mod(a, res / q, p) == 1 {
+            res /= q;
+        }
+    }
+    Some(res)
+}
+
+pub fn primitive_root(p: u64) -> u64 {
+    if p == 2 {
+        return 1;
+    }
+    let factors = factorize(p - 1);
+    (2..)
+        .find(|&g| {
+            factors
+                .iter()
+                .all(|&(q, _)| pow_mod(g, (p - 1) / q, p) != 1)
+        })
+        .unwrap()
+}
+
+pub(crate) fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        discrete_log, kth_root_mod, multiplicative_order, pow_mod, primitive_root, sqrt_mod,
+    };
+    use crate::prime::is_prime;
+
+    #[test]
+    fn discrete_log_matches_brute_force() {
+        for m in 1..120 {
+            for a in 0..m {
+                let powers = (0..2 * m).map(|x| pow_mod(a, x, m)).collect::<Vec<_>>();
+                for b in 0..m {
+                    let expected = powers.iter().position(|&y| y == b).map(|x| x as u64);
+                    assert_eq!(discrete_log(a, b, m), expected, "{a}^x = {b} mod {m}");
+                }
+            }
+        }
+        let m = 1_000_000_007 * 3;
+        let x = 123_456_789;
+        assert_eq!(
+            pow_mod(5, discrete_log(5, pow_mod(5, x, m), m).unwrap(), m),
+            pow_mod(5, x, m)
+        );
+    }
+
+    #[test]
+    fn roots_match_brute_force() {
+        for p in (2..200).filter(|&p| is_prime(p)) {
+            for a in 0..p {
+                let has_sqrt = (0..p).any(|x| x * x % p == a);
+                match sqrt_mod(a, p) {
+                    Some(x) => assert_eq!(x * x % p, a),
+                    None => assert!(!has_sqrt, "sqrt({a}) mod {p}"),
+                }
+                for k in 0..12 {
+                    let has_root = (0..p).any(|x| pow_mod(x, k, p) == a);
+                    match kth_root_mod(a, k, p) {
+                        Some(x) => assert_eq!(pow_mod(x, k, p), a, "{k}th root of {a} mod {p}"),
+                        None => assert!(!has_root, "{k}th root of {a} mod {p}"),
+                    }
+                }
+            }
+        }
+        let p = 998_244_353;
+        for k in [2, 7, 17, 119, 1 << 23, 998_244_352] {
+            let a = pow_mod(3, 1_234_567 * k, p);
+            let x = kth_root_mod(a, k, p).unwrap();
+            assert_eq!(pow_mod(x, k, p), a);
+        }
+    }
+
+    #[test]
+    fn order_and_primitive_root() {
+        for p in (2..300).filter(|&p| is_prime(p)) {
+            assert_eq!(multiplicative_order(0, p), None);
+            for a in 1..p {
+                let order = (1..p).find(|&e| pow_mod(a, e, p) == 1);
+                assert_eq!(multiplicative_order(a, p), order);
+            }
+            assert_eq!(multiplicative_order(primitive_root(p), p), Some(p - 1));
+        }
+        assert_eq!(primitive_root(998_244_353), 3);
+    }
+}
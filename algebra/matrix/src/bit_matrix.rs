@@ -0,0 +1,232 @@
+use super::ShapeMismatch;
+
+use std::ops::{Add, Mul};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitMatrix {
+    h: usize,
+    w: usize,
+    words: usize,
+    value: Box<[u64]>,
+}
+
+impl BitMatrix {
+    pub fn new(h: usize, w: usize) -> Self {
+        let words = w.div_ceil(64);
+        Self {
+            h,
+            w,
+            words,
+            value: vec![0; h * words].into_boxed_slice(),
+        }
+    }
+
+    pub fn new_from_2d(a: &[Vec<bool>]) -> Self {
+        let h = a.len();
+        let w = if h == 0 { 0 } else { a[0].len() };
+        let mut res = Self::new(h, w);
+        for (i, row) in a.iter().enumerate() {
+            for (j, &v) in row.iter().enumerate() {
+                res.set(i, j, v);
+            }
+        }
+        res
+    }
+
+    pub fn e(n: usize) -> Self {
+        let mut res = Self::new(n, n);
+        for i in 0..n {
+            res.set(i, i, true);
+        }
+        res
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.h, self.w)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        (self.row(i)[j / 64] >> (j % 64)) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, v: bool) {
+        let word = &mut self.row_mut(i)[j / 64];
+        if v {
+            *word |= 1 << (j % 64);
+        } else {
+            *word &= !(1 << (j % 64));
+        }
+    }
+
+    pub fn row(&self, i: usize) -> &[u64] {
+        &self.value[i * self.words..(i + 1) * self.words]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [u64] {
+        &mut self.value[i * self.words..(i + 1) * self.words]
+    }
+
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        for k in 0..self.words {
+            self.value.swap(i * self.words + k, j * self.words + k);
+        }
+    }
+
+    fn xor_row(&mut self, dst: usize, src: usize, from: usize) {
+        let words = self.words;
+        for k in from..words {
+            self.value[dst * words + k] ^= self.value[src * words + k];
+        }
+    }
+
+    pub fn reduced_row_echelon(&mut self) -> Vec<usize> {
+        let mut pivots = vec![];
+        for c in 0..self.w {
+            let r = pivots.len();
+            if r == self.h {
+                break;
+            }
+            if let Some(k) = (r..self.h).find(|&k| self.get(k, c)) {
+                self.swap_rows(r, k);
+                for i in 0..self.h {
+                    if i != r && self.get(i, c) {
+                        self.xor_row(i, r, c / 64);
+                    }
+                }
+                pivots.push(c);
+            }
+        }
+        pivots
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().reduced_row_echelon().len()
+    }
+
+    pub fn determinant(&self) -> bool {
+        assert_eq!(self.h, self.w);
+        self.rank() == self.h
+    }
+
+    pub fn solve(&self, b: &[bool]) -> Option<(Vec<bool>, Vec<Vec<bool>>)> {
+        assert_eq!(self.h, b.len());
+        let w = self.w;
+        let mut a = Self::new(self.h, w + 1);
+        for (i, &b) in b.iter().enumerate() {
+            a.row_mut(i)[..self.words].copy_from_slice(self.row(i));
+            a.set(i, w, b);
+        }
+        let pivots = a.reduced_row_echelon();
+        if pivots.last() == Some(&w) {
+            return None;
+        }
+        let mut particular = vec![false; w];
+        for (i, &p) in pivots.iter().enumerate() {
+            particular[p] = a.get(i, w);
+        }
+        let mut is_pivot = vec![false; w];
+        for &p in &pivots {
+            is_pivot[p] = true;
+        }
+        let mut kernel = vec![];
+        for f in (0..w).filter(|&f| !is_pivot[f]) {
+            let mut v = vec![false; w];
+            v[f] = true;
+            for (i, &p) in pivots.iter().enumerate() {
+                v[p] = a.get(i, f);
+            }
+            kernel.push(v);
+        }
+        Some((particular, kernel))
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, ShapeMismatch> {
+        if self.shape() != rhs.shape() {
+            return Err(ShapeMismatch {
+                lhs: self.shape(),
+                rhs: rhs.shape(),
+            });
+        }
+        let mut res = self.clone();
+        for (res, rhs) in res.value.iter_mut().zip(rhs.value.iter()) {
+            *res ^= *rhs;
+        }
+        Ok(res)
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, ShapeMismatch> {
+        if self.w != rhs.h {
+            return Err(ShapeMismatch {
+                lhs: self.shape(),
+                rhs: rhs.shape(),
+            });
+        }
+        const K: usize = 8;
+        let words = rhs.words;
+        let mut res = Self::new(self.h, rhs.w);
+        let mut table = vec![0u64; (1 << K) * words];
+        for k0 in (0..self.w).step_by(K) {
+            let k = K.min(self.w - k0);
+            for mask in 1..1usize << k {
+                let low = mask.trailing_zeros() as usize;
+                let prev = mask & (mask - 1);
+                for t in 0..words {
+                    table[mask * words + t] = table[prev * words + t] ^ rhs.row(k0 + low)[t];
+                }
+            }
+            for i in 0..self.h {
+                let word = self.row(i)[k0 / 64] >> (k0 % 64);
+                let mask = (word as usize) & ((1 << k) - 1);
+                if mask == 0 {
+                    continue;
+                }
+                for (r, t) in res
+                    .row_mut(i)
+                    .iter_mut()
+                    .zip(table[mask * words..(mask + 1) * words].iter())
+                {
+                    *r ^= *t;
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    pub fn pow(&self, mut k: u64) -> Self {
+        let mut res = Self::e(self.h);
+        let mut value = self.clone();
+        while k > 0 {
+            if k & 1 == 1 {
+                res = &res * &value;
+            }
+            value = &value * &value;
+            k >>= 1;
+        }
+        res
+    }
+}
+
+impl Add<&BitMatrix> for &BitMatrix {
+    type Output = BitMatrix;
+    fn add(self, rhs: &BitMatrix) -> Self::Output {
+        self.checked_add(rhs).unwrap()
+    }
+}
+impl Add for BitMatrix {
+    type Output = BitMatrix;
+    fn add(self, rhs: BitMatrix) -> Self::Output {
+        self.checked_add(&rhs).unwrap()
+    }
+}
+impl Mul<&BitMatrix> for &BitMatrix {
+    type Output = BitMatrix;
+    fn mul(self, rhs: &BitMatrix) -> Self::Output {
+        self.checked_mul(rhs).unwrap()
+    }
+}
+impl Mul for BitMatrix {
+    type Output = BitMatrix;
+    fn mul(self, rhs: BitMatrix) -> Self::Output {
+        self.checked_mul(&rhs).unwrap()
+    }
+}
@@ -1,6 +1,42 @@
-use std::ops::{Add, Index, IndexMut, Neg, Sub};
+pub mod bit_matrix;
+#[cfg(test)]
+mod tests;
+pub mod xor_basis;
 
-use algebra::{Field, One, Zero};
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub},
+};
+
+use algebra::{monoid::Semiring, operator::Arithmetic, Euclidean, Field, One, Ring, Zero};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShapeMismatch {
+    pub lhs: (usize, usize),
+    pub rhs: (usize, usize),
+}
+
+impl ShapeMismatch {
+    fn new<T: Copy>(lhs: &Matrix<T>, rhs: &Matrix<T>) -> Self {
+        Self {
+            lhs: lhs.shape(),
+            rhs: rhs.shape(),
+        }
+    }
+}
+
+impl Display for ShapeMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "shape mismatch: {}x{} and {}x{}",
+            self.lhs.0, self.lhs.1, self.rhs.0, self.rhs.1
+        )
+    }
+}
+
+impl Error for ShapeMismatch {}
 
 #[derive(Clone)]
 pub struct Matrix<T: Copy> {
@@ -32,6 +68,22 @@ impl<T: Copy> Matrix<T> {
         }
     }
 
+    pub fn shape(&self) -> (usize, usize) {
+        (self.h, self.w)
+    }
+
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        for k in 0..self.w {
+            self.value.swap(i * self.w + k, j * self.w + k);
+        }
+    }
+
+    fn swap_columns(&mut self, i: usize, j: usize) {
+        for row in self.value.chunks_exact_mut(self.w) {
+            row.swap(i, j);
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &[T]> {
         self.value.chunks_exact(self.w)
     }
@@ -42,64 +94,64 @@ impl<T: Copy> Matrix<T> {
 }
 
 impl<T: Copy + Add<Output = T>> Matrix<T> {
-    pub fn add(&self, rhs: Self) -> Self {
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, ShapeMismatch> {
+        if self.shape() != rhs.shape() {
+            return Err(ShapeMismatch::new(self, rhs));
+        }
         let mut res = self.clone();
         for (res, rhs) in res.value.iter_mut().zip(rhs.value.iter()) {
             *res = *res + *rhs;
         }
-        res
+        Ok(res)
     }
 }
 
 impl<T: Copy + Sub<Output = T>> Matrix<T> {
-    pub fn sub(&self, rhs: &Self) -> Self {
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, ShapeMismatch> {
+        if self.shape() != rhs.shape() {
+            return Err(ShapeMismatch::new(self, rhs));
+        }
         let mut res = self.clone();
         for (res, rhs) in res.value.iter_mut().zip(rhs.value.iter()) {
             *res = *res - *rhs;
         }
-        res
+        Ok(res)
     }
 }
 
-impl<T: Copy + Neg<Output = T>> Matrix<T> {
-    pub fn neg(&self) -> Self {
-        Self {
-            h: self.h,
-            w: self.w,
-            value: self.value.iter().map(|v| -*v).collect::<Box<_>>(),
+impl<T: Copy> Matrix<T> {
+    pub fn semiring_mul<S: Semiring<Value = T>>(&self, rhs: &Self) -> Result<Self, ShapeMismatch> {
+        if self.w != rhs.h {
+            return Err(ShapeMismatch::new(self, rhs));
         }
-    }
-}
-
-impl<T: Copy + Zero + One> Matrix<T> {
-    pub fn mul(&self, rhs: &Self) -> Self {
-        let mut value = Vec::with_capacity(self.h * rhs.w);
-        for (res_row, lhs_row) in value.chunks_exact_mut(self.h).zip(self.iter()) {
-            for (lhs_val, rhs_row) in lhs_row.iter().zip(rhs.iter()) {
-                for (res, rhs_val) in res_row.iter_mut().zip(rhs_row.iter()) {
-                    *res = *res + *lhs_val * *rhs_val;
+        const BLOCK: usize = 64;
+        let (n, m, l) = (self.h, self.w, rhs.w);
+        let mut value = vec![S::zero(); n * l];
+        for k0 in (0..m).step_by(BLOCK) {
+            let k1 = (k0 + BLOCK).min(m);
+            for j0 in (0..l).step_by(BLOCK) {
+                let j1 = (j0 + BLOCK).min(l);
+                for (res_row, lhs_row) in value.chunks_exact_mut(l).zip(self.iter()) {
+                    let res_row = &mut res_row[j0..j1];
+                    for (lhs_val, rhs_row) in lhs_row[k0..k1].iter().zip(rhs.iter().skip(k0)) {
+                        for (res, rhs_val) in res_row.iter_mut().zip(rhs_row[j0..j1].iter()) {
+                            *res = S::add(res, &S::mul(lhs_val, rhs_val));
+                        }
+                    }
                 }
             }
         }
-        Self {
-            h: self.h,
-            w: rhs.w,
+        Ok(Self {
+            h: n,
+            w: l,
             value: value.into_boxed_slice(),
-        }
-    }
-
-    pub fn zero(h: usize, w: usize) -> Self {
-        Self {
-            h,
-            w,
-            value: vec![T::zero(); h * w].into_boxed_slice(),
-        }
+        })
     }
 
-    pub fn e(n: usize) -> Self {
-        let mut value = vec![T::zero(); n * n];
+    pub fn semiring_e<S: Semiring<Value = T>>(n: usize) -> Self {
+        let mut value = vec![S::zero(); n * n];
         for i in 0..n {
-            value[i * (n + 1)] = T::one();
+            value[i * (n + 1)] = S::one();
         }
         Self {
             h: n,
@@ -108,21 +160,43 @@ impl<T: Copy + Zero + One> Matrix<T> {
         }
     }
 
-    pub fn pow(&self, mut k: u64) -> Self {
+    pub fn semiring_pow<S: Semiring<Value = T>>(&self, mut k: u64) -> Self {
         let n = self.h;
-        let mut res = Self::e(n);
+        let mut res = Self::semiring_e::<S>(n);
         let mut value = self.clone();
         while k > 0 {
             if k & 1 == 1 {
-                res = Self::mul(&res, &value);
+                res = res.semiring_mul::<S>(&value).unwrap();
             }
-            value = Self::mul(&value, &value);
+            value = value.semiring_mul::<S>(&value).unwrap();
             k >>= 1;
         }
         res
     }
 }
 
+impl<T: Copy + Zero + One> Matrix<T> {
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, ShapeMismatch> {
+        self.semiring_mul::<Arithmetic<T>>(rhs)
+    }
+
+    pub fn zero(h: usize, w: usize) -> Self {
+        Self {
+            h,
+            w,
+            value: vec![T::zero(); h * w].into_boxed_slice(),
+        }
+    }
+
+    pub fn e(n: usize) -> Self {
+        Self::semiring_e::<Arithmetic<T>>(n)
+    }
+
+    pub fn pow(&self, k: u64) -> Self {
+        self.semiring_pow::<Arithmetic<T>>(k)
+    }
+}
+
 impl<T: Copy + Field> Matrix<T> {
     pub fn determinant(&self) -> T {
         let n = self.h;
@@ -158,34 +232,199 @@ impl<T: Copy + Field> Matrix<T> {
     }
 
     pub fn gaussian_elimination(&mut self) -> usize {
+        self.reduced_row_echelon().len()
+    }
+
+    pub fn row_echelon(&mut self) -> Vec<usize> {
+        self.eliminate(false)
+    }
+
+    pub fn reduced_row_echelon(&mut self) -> Vec<usize> {
+        self.eliminate(true)
+    }
+
+    fn eliminate(&mut self, reduced: bool) -> Vec<usize> {
         let h = self.h;
         let w = self.w;
-        let mut x = 0;
+        let mut pivots = vec![];
         let mut tmp = Vec::with_capacity(w);
         for y in 0..w {
+            let x = pivots.len();
             if let Some(k) = (x..h).find(|k| !self[*k][y].is_zero()) {
                 for j in 0..w {
                     self.value.swap(x * w + j, k * w + j);
                 }
-                let inv = T::one() / self[x][y];
-                for v in self[x].iter_mut().skip(y) {
-                    *v = *v * inv;
+                if reduced {
+                    let inv = T::one() / self[x][y];
+                    for v in self[x].iter_mut().skip(y) {
+                        *v = *v * inv;
+                    }
                 }
+                let inv = T::one() / self[x][y];
                 tmp.clear();
                 tmp.extend_from_slice(&self[x]);
                 for (i, r) in self.iter_mut().enumerate() {
-                    if i == x {
+                    if i == x || (!reduced && i < x) {
                         continue;
                     }
-                    let p = r[y];
+                    let p = r[y] * inv;
                     for (v, tmp) in r[y..].iter_mut().zip(tmp[y..].iter()) {
                         *v = *v - p * *tmp;
                     }
                 }
-                x += 1;
+                pivots.push(y);
+            }
+        }
+        pivots
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().row_echelon().len()
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut value = Vec::with_capacity(2 * n * n);
+        for (i, row) in self.iter().enumerate() {
+            value.extend_from_slice(row);
+            value.extend((0..n).map(|j| if i == j { T::one() } else { T::zero() }));
+        }
+        let mut a = Self::new(n, 2 * n, &value);
+        let pivots = a.reduced_row_echelon();
+        if pivots.iter().copied().take(n).ne(0..n) {
+            return None;
+        }
+        let value = a
+            .iter()
+            .flat_map(|row| row[n..].to_vec())
+            .collect::<Vec<_>>();
+        Some(Self::new(n, n, &value))
+    }
+
+    pub fn solve(&self, b: &[T]) -> Option<(Vec<T>, Vec<Vec<T>>)> {
+        assert_eq!(self.h, b.len());
+        let w = self.w;
+        let mut value = Vec::with_capacity(self.h * (w + 1));
+        for (row, &b) in self.iter().zip(b) {
+            value.extend_from_slice(row);
+            value.push(b);
+        }
+        let mut a = Self::new(self.h, w + 1, &value);
+        let pivots = a.reduced_row_echelon();
+        if pivots.last() == Some(&w) {
+            return None;
+        }
+        let mut particular = vec![T::zero(); w];
+        for (i, &p) in pivots.iter().enumerate() {
+            particular[p] = a[i][w];
+        }
+        let mut is_pivot = vec![false; w];
+        for &p in &pivots {
+            is_pivot[p] = true;
+        }
+        let mut kernel = vec![];
+        for f in (0..w).filter(|&f| !is_pivot[f]) {
+            let mut v = vec![T::zero(); w];
+            v[f] = T::one();
+            for (i, &p) in pivots.iter().enumerate() {
+                v[p] = -a[i][f];
+            }
+            kernel.push(v);
+        }
+        Some((particular, kernel))
+    }
+}
+
+impl<T: Copy + Ring + Div<Output = T>> Matrix<T> {
+    pub fn determinant_bareiss(&self) -> T {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut a = self.clone();
+        let mut res = T::one();
+        let mut prev = T::one();
+        for k in 0..n {
+            if a[k][k].is_zero() {
+                match (k + 1..n).find(|&i| !a[i][k].is_zero()) {
+                    Some(i) => {
+                        a.swap_rows(k, i);
+                        res = -res;
+                    }
+                    None => return T::zero(),
+                }
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    a[i][j] = (a[i][j] * a[k][k] - a[i][k] * a[k][j]) / prev;
+                }
+            }
+            prev = a[k][k];
+        }
+        res * prev
+    }
+}
+
+impl<T: Copy + Euclidean> Matrix<T> {
+    pub fn determinant_euclid(&self) -> T {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut a = self.clone();
+        let mut res = T::one();
+        for k in 0..n {
+            for i in k + 1..n {
+                while !a[i][k].is_zero() {
+                    let q = a[k][k].quotient(&a[i][k]);
+                    for j in k..n {
+                        a[k][j] = a[k][j] - q * a[i][j];
+                    }
+                    a.swap_rows(k, i);
+                    res = -res;
+                }
+            }
+            res = res * a[k][k];
+        }
+        res
+    }
+
+    pub fn characteristic_polynomial(&self) -> Vec<T> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut a = self.clone();
+        for k in 0..n.saturating_sub(2) {
+            let p = k + 1;
+            for i in k + 2..n {
+                while !a[i][k].is_zero() {
+                    let q = a[p][k].quotient(&a[i][k]);
+                    for j in 0..n {
+                        a[p][j] = a[p][j] - q * a[i][j];
+                    }
+                    for j in 0..n {
+                        a[j][i] = a[j][i] + q * a[j][p];
+                    }
+                    a.swap_rows(p, i);
+                    a.swap_columns(p, i);
+                }
             }
         }
-        x
+
+        let mut res = vec![vec![T::one()]];
+        for k in 0..n {
+            let mut next = vec![T::zero(); k + 2];
+            for (d, &c) in res[k].iter().enumerate() {
+                next[d + 1] = next[d + 1] + c;
+                next[d] = next[d] - a[k][k] * c;
+            }
+            let mut prod = T::one();
+            for i in (0..k).rev() {
+                prod = prod * a[i + 1][i];
+                let coef = prod * a[i][k];
+                for (d, &c) in res[i].iter().enumerate() {
+                    next[d] = next[d] - coef * c;
+                }
+            }
+            res.push(next);
+        }
+        res.pop().unwrap()
     }
 }
 
@@ -201,3 +440,59 @@ impl<T: Copy> IndexMut<usize> for Matrix<T> {
         &mut self.value[index * self.w..(index + 1) * self.w]
     }
 }
+
+macro_rules! matrix_ops {
+    ($(
+            $trait:ident,
+            $fn:ident,
+            $checked:ident,
+            [$($bound:tt)*],
+    )*) => {$(
+        impl<T: $($bound)*> $trait<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $fn(self, rhs: &Matrix<T>) -> Self::Output {
+                self.$checked(rhs).unwrap()
+            }
+        }
+        impl<T: $($bound)*> $trait<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $fn(self, rhs: Matrix<T>) -> Self::Output {
+                self.$checked(&rhs).unwrap()
+            }
+        }
+        impl<T: $($bound)*> $trait<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $fn(self, rhs: &Matrix<T>) -> Self::Output {
+                self.$checked(rhs).unwrap()
+            }
+        }
+        impl<T: $($bound)*> $trait<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $fn(self, rhs: Matrix<T>) -> Self::Output {
+                self.$checked(&rhs).unwrap()
+            }
+        }
+    )*};
+}
+matrix_ops! {
+    Add, add, checked_add, [Copy + Add<Output = T>],
+    Sub, sub, checked_sub, [Copy + Sub<Output = T>],
+    Mul, mul, checked_mul, [Copy + Zero + One],
+}
+
+impl<T: Copy + Neg<Output = T>> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn neg(self) -> Self::Output {
+        Matrix {
+            h: self.h,
+            w: self.w,
+            value: self.value.iter().map(|v| -*v).collect::<Box<_>>(),
+        }
+    }
+}
+impl<T: Copy + Neg<Output = T>> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
@@ -0,0 +1,499 @@
+use super::{bit_matrix::BitMatrix, xor_basis::XorBasis, Matrix, ShapeMismatch};
+use algebra::{
+    monoid::Semiring,
+    operator::{Arithmetic, Boolean, MaxPlus, MinPlus},
+    Euclidean, Ring,
+};
+use galois_field::GF;
+use xorshift::XorShift;
+
+fn naive(a: &Matrix<i64>, b: &Matrix<i64>) -> Vec<Vec<i64>> {
+    let ((n, m), (_, l)) = (a.shape(), b.shape());
+    (0..n)
+        .map(|i| {
+            (0..l)
+                .map(|j| (0..m).map(|k| a[i][k] * b[k][j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+fn random(rng: &mut XorShift, h: usize, w: usize) -> Matrix<i64> {
+    let value = (0..h * w)
+        .map(|_| rng.below(2001) as i64 - 1000)
+        .collect::<Vec<_>>();
+    Matrix::new(h, w, &value)
+}
+
+const SHAPES: [usize; 8] = [1, 2, 3, 17, 63, 64, 65, 130];
+
+#[test]
+fn mul_matches_naive() {
+    let mut rng = XorShift::default();
+    for &n in &SHAPES {
+        for &m in &SHAPES {
+            let l = SHAPES[rng.below(SHAPES.len() as u64) as usize];
+            let a = random(&mut rng, n, m);
+            let b = random(&mut rng, m, l);
+            let c = &a * &b;
+            assert_eq!(c.shape(), (n, l));
+            assert!(c.iter().eq(naive(&a, &b).iter().map(|r| &r[..])));
+            assert!(a.checked_mul(&b).unwrap().iter().eq(c.iter()));
+        }
+    }
+}
+
+#[test]
+fn add_sub_neg_are_elementwise() {
+    let mut rng = XorShift::default();
+    for &n in &SHAPES {
+        let m = SHAPES[rng.below(SHAPES.len() as u64) as usize];
+        let (a, b) = (random(&mut rng, n, m), random(&mut rng, n, m));
+        let (sum, difference, negated) = (&a + &b, &a - &b, -&a);
+        for i in 0..n {
+            for j in 0..m {
+                assert_eq!(sum[i][j], a[i][j] + b[i][j]);
+                assert_eq!(difference[i][j], a[i][j] - b[i][j]);
+                assert_eq!(negated[i][j], -a[i][j]);
+            }
+        }
+        assert!(a.checked_add(&b).unwrap().iter().eq(sum.iter()));
+        assert!(a.checked_sub(&b).unwrap().iter().eq(difference.iter()));
+        assert!((&sum - &b).iter().eq(a.iter()));
+        assert!((&a + &negated).iter().eq(Matrix::zero(n, m).iter()));
+    }
+}
+
+#[test]
+fn shape_mismatch() {
+    let a = Matrix::new(2, 3, &[0i64; 6]);
+    let b = Matrix::new(2, 3, &[0i64; 6]);
+    let err = ShapeMismatch {
+        lhs: (2, 3),
+        rhs: (2, 3),
+    };
+    assert_eq!(a.checked_mul(&b).err(), Some(err));
+    assert!(a.checked_add(&b).is_ok());
+    let c = Matrix::new(3, 2, &[0i64; 6]);
+    assert_eq!(
+        a.checked_add(&c).err(),
+        Some(ShapeMismatch {
+            lhs: (2, 3),
+            rhs: (3, 2)
+        })
+    );
+    assert!(a.checked_sub(&c).is_err());
+    assert_eq!(err.to_string(), "shape mismatch: 2x3 and 2x3");
+}
+
+#[test]
+#[should_panic]
+fn mul_panics_on_shape_mismatch() {
+    let a = Matrix::new(2, 3, &[0i64; 6]);
+    let _ = &a * &a;
+}
+
+fn semiring_naive<S: Semiring>(a: &Matrix<S::Value>, b: &Matrix<S::Value>) -> Vec<Vec<S::Value>>
+where
+    S::Value: Copy,
+{
+    let ((n, m), (_, l)) = (a.shape(), b.shape());
+    (0..n)
+        .map(|i| {
+            (0..l)
+                .map(|j| {
+                    (0..m).fold(S::zero(), |acc, k| {
+                        S::add(&acc, &S::mul(&a[i][k], &b[k][j]))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn check_semiring<S: Semiring>(rng: &mut XorShift, f: impl Fn(u64) -> S::Value)
+where
+    S::Value: Copy + PartialEq + std::fmt::Debug,
+{
+    let mut random = |h: usize, w: usize| {
+        let value = (0..h * w).map(|_| f(rng.next_u64())).collect::<Vec<_>>();
+        Matrix::new(h, w, &value)
+    };
+    for &n in &SHAPES {
+        for &m in &SHAPES[..6] {
+            let (a, b) = (random(n, m), random(m, n));
+            let c = a.semiring_mul::<S>(&b).unwrap();
+            assert!(c
+                .iter()
+                .eq(semiring_naive::<S>(&a, &b).iter().map(|r| &r[..])));
+        }
+    }
+    for &n in &SHAPES[..5] {
+        let a = random(n, n);
+        let mut expected = Matrix::semiring_e::<S>(n);
+        for k in 0..6 {
+            assert!(a.semiring_pow::<S>(k).iter().eq(expected.iter()));
+            expected = expected.semiring_mul::<S>(&a).unwrap();
+        }
+    }
+}
+
+fn tropical<S: Semiring<Value = i64>>(x: u64) -> i64 {
+    if x.is_multiple_of(4) {
+        S::zero()
+    } else {
+        (x % 200) as i64 - 100
+    }
+}
+
+#[test]
+fn semiring_mul_matches_naive() {
+    let mut rng = XorShift::default();
+    check_semiring::<Arithmetic<i64>>(&mut rng, |x| (x % 7) as i64 - 3);
+    check_semiring::<MinPlus<i64>>(&mut rng, tropical::<MinPlus<i64>>);
+    check_semiring::<MaxPlus<i64>>(&mut rng, tropical::<MaxPlus<i64>>);
+    check_semiring::<Boolean>(&mut rng, |x| x.is_multiple_of(5));
+}
+
+#[test]
+fn tropical_paths_saturate() {
+    let inf = MinPlus::<i64>::zero();
+    let a = Matrix::new(2, 2, &[inf - 1, inf, inf, 0]);
+    assert!(a
+        .semiring_pow::<MinPlus<i64>>(3)
+        .iter()
+        .eq(Matrix::new(2, 2, &[inf, inf, inf, 0]).iter()));
+    let b = Matrix::new(2, 2, &[0, 1 << 62, 1 << 62, 0]);
+    let c = b.semiring_mul::<MaxPlus<i64>>(&b).unwrap();
+    assert_eq!(c[0][0], i64::MAX);
+}
+
+#[test]
+fn semiring_shape_mismatch() {
+    let a = Matrix::new(2, 3, &[0i64; 6]);
+    assert_eq!(
+        a.semiring_mul::<MinPlus<i64>>(&a).err(),
+        Some(ShapeMismatch {
+            lhs: (2, 3),
+            rhs: (2, 3)
+        })
+    );
+}
+
+fn random_gf<const MOD: u32>(rng: &mut XorShift, h: usize, w: usize) -> Matrix<GF<MOD>> {
+    // mostly sparse so that singular and rank-deficient cases are common
+    let value = (0..h * w)
+        .map(|_| match rng.below(3) {
+            0 => GF::new(rng.below(MOD as u64) as u32),
+            _ => GF::new(0),
+        })
+        .collect::<Vec<_>>();
+    Matrix::new(h, w, &value)
+}
+
+fn apply<const MOD: u32>(a: &Matrix<GF<MOD>>, x: &[GF<MOD>]) -> Vec<GF<MOD>> {
+    a.iter()
+        .map(|row| row.iter().zip(x).map(|(&a, &x)| a * x).sum())
+        .collect()
+}
+
+#[test]
+fn reduced_row_echelon_form() {
+    let mut rng = XorShift::default();
+    for _ in 0..300 {
+        let (h, w) = (1 + rng.below(6) as usize, 1 + rng.below(6) as usize);
+        let a = random_gf::<998_244_353>(&mut rng, h, w);
+        let mut r = a.clone();
+        let pivots = r.reduced_row_echelon();
+        assert_eq!(pivots.len(), a.rank());
+        assert!(pivots.windows(2).all(|p| p[0] < p[1]));
+        for (i, &p) in pivots.iter().enumerate() {
+            assert!(r[i][..p].iter().all(|v| *v == GF::new(0)));
+            for k in 0..h {
+                assert_eq!(r[k][p], GF::new((i == k) as u32));
+            }
+        }
+        assert!((pivots.len()..h).all(|i| r[i].iter().all(|v| *v == GF::new(0))));
+
+        let mut e = a.clone();
+        let echelon = e.row_echelon();
+        assert_eq!(echelon, pivots);
+        assert_eq!(a.clone().gaussian_elimination(), pivots.len());
+    }
+}
+
+#[test]
+fn solve_matches_exhaustive_search() {
+    let mut rng = XorShift::default();
+    for _ in 0..300 {
+        let (h, w) = (1 + rng.below(4) as usize, 1 + rng.below(4) as usize);
+        let a = random_gf::<3>(&mut rng, h, w);
+        let b = (0..h)
+            .map(|_| GF::new(rng.below(3) as u32))
+            .collect::<Vec<_>>();
+        let all = (0..3usize.pow(w as u32))
+            .map(|mut k| {
+                (0..w)
+                    .map(|_| {
+                        let d = k % 3;
+                        k /= 3;
+                        GF::<3>::new(d as u32)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let solutions = all.iter().filter(|x| apply(&a, x) == b).count();
+        match a.solve(&b) {
+            None => assert_eq!(solutions, 0),
+            Some((x, kernel)) => {
+                assert_eq!(apply(&a, &x), b);
+                assert_eq!(kernel.len(), w - a.rank());
+                assert_eq!(solutions, 3usize.pow(kernel.len() as u32));
+                for v in &kernel {
+                    assert!(apply(&a, v).iter().all(|v| *v == GF::new(0)));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn inverse() {
+    let mut rng = XorShift::default();
+    for _ in 0..300 {
+        let n = 1 + rng.below(6) as usize;
+        let a = random_gf::<998_244_353>(&mut rng, n, n);
+        match a.inverse() {
+            Some(inv) => {
+                assert_eq!(a.rank(), n);
+                assert!((&a * &inv).iter().eq(Matrix::e(n).iter()));
+                assert!((&inv * &a).iter().eq(Matrix::e(n).iter()));
+            }
+            None => assert!(a.rank() < n),
+        }
+    }
+}
+
+fn leibniz<T: Copy + Ring>(a: &[Vec<T>]) -> T {
+    fn permutations(n: usize, k: usize, perm: &mut Vec<usize>, out: &mut Vec<(Vec<usize>, bool)>) {
+        if k == n {
+            let inversions = (0..n)
+                .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                .filter(|&(i, j)| perm[i] > perm[j])
+                .count();
+            out.push((perm.clone(), inversions % 2 == 1));
+            return;
+        }
+        for i in k..n {
+            perm.swap(k, i);
+            permutations(n, k + 1, perm, out);
+            perm.swap(k, i);
+        }
+    }
+    let n = a.len();
+    let mut all = vec![];
+    permutations(n, 0, &mut (0..n).collect(), &mut all);
+    all.iter().fold(T::zero(), |acc, (perm, odd)| {
+        let term = (0..n).fold(T::one(), |acc, i| acc * a[i][perm[i]]);
+        if *odd {
+            acc - term
+        } else {
+            acc + term
+        }
+    })
+}
+
+fn to_rows<T: Copy>(a: &Matrix<T>) -> Vec<Vec<T>> {
+    a.iter().map(|r| r.to_vec()).collect()
+}
+
+#[test]
+fn determinants_match_leibniz() {
+    let mut rng = XorShift::default();
+    for _ in 0..300 {
+        let n = 1 + rng.below(6) as usize;
+        let a = random_gf::<998_244_353>(&mut rng, n, n);
+        assert_eq!(a.determinant(), leibniz(&to_rows(&a)));
+        assert_eq!(a.determinant_bareiss(), leibniz(&to_rows(&a)));
+
+        let value = (0..n * n)
+            .map(|_| match rng.below(3) {
+                0 => 0,
+                _ => rng.below(21) as i64 - 10,
+            })
+            .collect::<Vec<_>>();
+        let a = Matrix::new(n, n, &value);
+        let expected = leibniz(&to_rows(&a));
+        assert_eq!(a.determinant_bareiss(), expected);
+        assert_eq!(a.determinant_euclid(), expected);
+    }
+}
+
+fn check_characteristic_polynomial<T>(a: &Matrix<T>, points: impl Iterator<Item = T>)
+where
+    T: Copy + Euclidean + PartialEq + std::fmt::Debug,
+{
+    let n = a.shape().0;
+    let p = a.characteristic_polynomial();
+    assert_eq!(p.len(), n + 1);
+    assert!(p[n].is_one());
+    for x in points {
+        let shifted = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if i == j { x - a[i][j] } else { -a[i][j] })
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        let at_x = p.iter().rev().fold(T::zero(), |acc, &c| acc * x + c);
+        assert_eq!(at_x, leibniz(&shifted));
+    }
+}
+
+#[test]
+fn characteristic_polynomial_matches_leibniz() {
+    let mut rng = XorShift::default();
+    for _ in 0..200 {
+        let n = 1 + rng.below(6) as usize;
+        let a = random_gf::<998_244_353>(&mut rng, n, n);
+        check_characteristic_polynomial(&a, (0..=n as u32).map(GF::new));
+
+        // entries grow during the integer Hessenberg reduction, so keep them small
+        let n = 1 + rng.below(4) as usize;
+        let value = (0..n * n)
+            .map(|_| rng.below(7) as i128 - 3)
+            .collect::<Vec<_>>();
+        check_characteristic_polynomial(&Matrix::new(n, n, &value), -2..=2);
+    }
+}
+
+fn random_bits(rng: &mut XorShift, h: usize, w: usize) -> Vec<Vec<bool>> {
+    (0..h)
+        .map(|_| (0..w).map(|_| rng.below(3) == 0).collect())
+        .collect()
+}
+
+fn to_gf2(a: &[Vec<bool>], h: usize, w: usize) -> Matrix<GF<2>> {
+    let value = (0..h * w)
+        .map(|k| GF::new(a[k / w][k % w] as u32))
+        .collect::<Vec<_>>();
+    Matrix::new(h, w, &value)
+}
+
+fn bits_of(a: &BitMatrix) -> Vec<Vec<bool>> {
+    let (h, w) = a.shape();
+    (0..h)
+        .map(|i| (0..w).map(|j| a.get(i, j)).collect())
+        .collect()
+}
+
+#[test]
+fn bit_matrix_matches_gf2() {
+    let mut rng = XorShift::default();
+    let shapes = [1, 2, 7, 8, 9, 63, 64, 65, 130];
+    for &n in &shapes {
+        for &m in &shapes {
+            let l = shapes[rng.below(shapes.len() as u64) as usize];
+            let (a, b, c) = (
+                random_bits(&mut rng, n, m),
+                random_bits(&mut rng, m, l),
+                random_bits(&mut rng, n, m),
+            );
+            let (x, y, z) = (
+                BitMatrix::new_from_2d(&a),
+                BitMatrix::new_from_2d(&b),
+                BitMatrix::new_from_2d(&c),
+            );
+            let (p, q, r) = (to_gf2(&a, n, m), to_gf2(&b, m, l), to_gf2(&c, n, m));
+            let to_bool = |a: &Matrix<GF<2>>| {
+                a.iter()
+                    .map(|r| r.iter().map(|v| *v == GF::new(1)).collect::<Vec<_>>())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(bits_of(&(&x * &y)), to_bool(&(&p * &q)));
+            assert_eq!(bits_of(&(&x + &z)), to_bool(&(&p + &r)));
+            assert_eq!(x.rank(), p.rank());
+            if n == m {
+                assert_eq!(x.determinant(), p.determinant() == GF::new(1));
+                let mut expected = BitMatrix::e(n);
+                for k in 0..4 {
+                    assert_eq!(x.pow(k), expected);
+                    expected = &expected * &x;
+                }
+            }
+            assert_eq!(
+                x.checked_mul(&z).err(),
+                (m != n).then_some(ShapeMismatch {
+                    lhs: (n, m),
+                    rhs: (n, m)
+                })
+            );
+        }
+    }
+}
+
+#[test]
+fn bit_matrix_solve_matches_exhaustive_search() {
+    let mut rng = XorShift::default();
+    for _ in 0..300 {
+        let (h, w) = (1 + rng.below(8) as usize, 1 + rng.below(10) as usize);
+        let a = random_bits(&mut rng, h, w);
+        let b = (0..h).map(|_| rng.below(2) == 1).collect::<Vec<_>>();
+        let apply = |x: &[bool]| {
+            a.iter()
+                .map(|row| row.iter().zip(x).filter(|(&a, &x)| a && x).count() % 2 == 1)
+                .collect::<Vec<_>>()
+        };
+        let solutions = (0..1usize << w)
+            .filter(|k| apply(&(0..w).map(|j| k >> j & 1 == 1).collect::<Vec<_>>()) == b)
+            .count();
+        let m = BitMatrix::new_from_2d(&a);
+        match m.solve(&b) {
+            None => assert_eq!(solutions, 0),
+            Some((x, kernel)) => {
+                assert_eq!(apply(&x), b);
+                assert_eq!(kernel.len(), w - m.rank());
+                assert_eq!(solutions, 1 << kernel.len());
+                for v in &kernel {
+                    assert!(apply(v).iter().all(|&v| !v));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn xor_basis_matches_span() {
+    let mut rng = XorShift::default();
+    for _ in 0..200 {
+        let mut basis = XorBasis::new();
+        let mut span = vec![0u64];
+        for _ in 0..rng.below(10) {
+            let x = rng.below(1 << 8);
+            let fresh = !span.contains(&x);
+            assert_eq!(basis.insert(x), fresh);
+            if fresh {
+                span = span.iter().flat_map(|&s| [s, s ^ x]).collect();
+            }
+        }
+        span.sort_unstable();
+        assert_eq!(1 << basis.rank(), span.len());
+        for x in 0..1 << 8 {
+            assert_eq!(basis.contains(x), span.binary_search(&x).is_ok());
+            assert_eq!(basis.max_xor(x), span.iter().map(|&s| s ^ x).max().unwrap());
+        }
+        for (k, &s) in span.iter().enumerate() {
+            assert_eq!(basis.kth_xor(k as u64), Some(s));
+        }
+        assert_eq!(basis.kth_xor(span.len() as u64), None);
+    }
+
+    let mut basis = XorBasis::default();
+    for b in 0..64 {
+        assert!(basis.insert(rng.next_u64() | 1 << b));
+    }
+    assert_eq!(basis.rank(), 64);
+    assert_eq!(basis.kth_xor(u64::MAX), Some(u64::MAX));
+    assert_eq!(basis.max_xor(0), u64::MAX);
+    assert!(!basis.insert(rng.next_u64()));
+}
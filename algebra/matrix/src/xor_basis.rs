@@ -0,0 +1,74 @@
+#[derive(Clone, Debug)]
+pub struct XorBasis {
+    basis: [u64; 64],
+    rank: usize,
+}
+
+impl XorBasis {
+    pub fn new() -> Self {
+        Self {
+            basis: [0; 64],
+            rank: 0,
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    fn reduce(&self, mut x: u64) -> u64 {
+        for b in (0..64).rev() {
+            if (x >> b) & 1 == 1 && self.basis[b] != 0 {
+                x ^= self.basis[b];
+            }
+        }
+        x
+    }
+
+    pub fn insert(&mut self, x: u64) -> bool {
+        let x = self.reduce(x);
+        if x == 0 {
+            return false;
+        }
+        let b = 63 - x.leading_zeros() as usize;
+        for v in self.basis.iter_mut().skip(b + 1) {
+            if (*v >> b) & 1 == 1 {
+                *v ^= x;
+            }
+        }
+        self.basis[b] = x;
+        self.rank += 1;
+        true
+    }
+
+    pub fn contains(&self, x: u64) -> bool {
+        self.reduce(x) == 0
+    }
+
+    pub fn max_xor(&self, x: u64) -> u64 {
+        let mut x = x;
+        for &v in self.basis.iter().rev() {
+            x = x.max(x ^ v);
+        }
+        x
+    }
+
+    pub fn kth_xor(&self, k: u64) -> Option<u64> {
+        if self.rank < 64 && k >> self.rank != 0 {
+            return None;
+        }
+        let mut res = 0;
+        for (i, &v) in self.basis.iter().filter(|&&v| v != 0).enumerate() {
+            if (k >> i) & 1 == 1 {
+                res ^= v;
+            }
+        }
+        Some(res)
+    }
+}
+
+impl Default for XorBasis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,31 @@
+use modint::{montgomery::MontgomeryModInt, ModInt};
+
+use std::time::Instant;
+
+const MOD: u64 = (1 << 61) - 1;
+const STEPS: usize = 20_000_000;
+
+fn main() {
+    let start = Instant::now();
+    let (mut x, y) = (
+        ModInt::<MOD>::new(3),
+        ModInt::<MOD>::new(0x9e37_79b9_7f4a_7c15),
+    );
+    for _ in 0..STEPS {
+        x = std::hint::black_box(x * y);
+    }
+    let plain = start.elapsed();
+
+    let start = Instant::now();
+    let (mut z, w) = (
+        MontgomeryModInt::<MOD>::new(3),
+        MontgomeryModInt::<MOD>::new(0x9e37_79b9_7f4a_7c15),
+    );
+    for _ in 0..STEPS {
+        z = std::hint::black_box(z * w);
+    }
+    let montgomery = start.elapsed();
+
+    assert_eq!(x.to_string(), z.to_string());
+    println!("ModInt: {:?}, MontgomeryModInt: {:?}", plain, montgomery);
+}
@@ -0,0 +1,319 @@
+use algebra::{Abelian, Euclidean, Exponent, Field, Group, One, Ring, Zero};
+
+use std::{
+    fmt::{Debug, Display},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+};
+
+const ID_LIMIT: usize = 8;
+
+static MODULUS: [AtomicU32; ID_LIMIT] = [const { AtomicU32::new(998_244_353) }; ID_LIMIT];
+static BARRETT: [AtomicU64; ID_LIMIT] =
+    [const { AtomicU64::new(u64::MAX / 998_244_353 + 1) }; ID_LIMIT];
+
+/// Integers modulo a runtime modulus, one modulus per `ID` (`ID < 8`).
+///
+/// `Field` is implemented for any modulus, but only holds when it is prime;
+/// use [`DynamicModInt::inv`] for a checked inverse under a composite modulus.
+///
+/// # Panics
+///
+/// Division panics if the divisor is not invertible, which for a prime modulus
+/// only happens when dividing by zero.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynamicModInt<const ID: usize> {
+    value: u32,
+}
+
+impl<const ID: usize> DynamicModInt<ID> {
+    pub fn set_modulus(modulus: u32) {
+        const { assert!(ID < ID_LIMIT) };
+        assert!(modulus >= 1);
+        MODULUS[ID].store(modulus, Ordering::Relaxed);
+        BARRETT[ID].store(
+            (u64::MAX / modulus as u64).wrapping_add(1),
+            Ordering::Relaxed,
+        );
+    }
+
+    pub fn modulus() -> u32 {
+        const { assert!(ID < ID_LIMIT) };
+        MODULUS[ID].load(Ordering::Relaxed)
+    }
+
+    pub fn new(value: u64) -> Self {
+        Self {
+            value: (value % Self::modulus() as u64) as u32,
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn pow<E: Exponent>(&self, exp: E) -> Self {
+        let mut exp = exp.to_u128();
+        let mut res = Self::new(1);
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        res
+    }
+
+    pub fn pow_decimal(&self, exp: &str) -> Self {
+        exp.bytes()
+            .fold(Self::one(), |acc, d| acc.pow(10u32) * self.pow(d - b'0'))
+    }
+
+    pub fn inv(&self) -> Option<Self> {
+        let (mut a, mut b) = (self.value as i64, Self::modulus() as i64);
+        let (mut x, mut y) = (1i64, 0i64);
+        while b > 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        if a == 1 {
+            Some(Self::from(x))
+        } else {
+            None
+        }
+    }
+
+    fn reduce(z: u64) -> u32 {
+        let m = Self::modulus() as u64;
+        let x = ((z as u128 * BARRETT[ID].load(Ordering::Relaxed) as u128) >> 64) as u64;
+        let (v, borrow) = z.overflowing_sub(x * m);
+        if borrow {
+            v.wrapping_add(m) as u32
+        } else {
+            v as u32
+        }
+    }
+}
+
+macro_rules! dynamic_modint_new_from_signed {
+    ($($t:ty), *) => {
+        $(
+            impl<const ID: usize> From<$t> for DynamicModInt<ID> {
+                fn from(x: $t) -> Self {
+                    Self {
+                        value: (x as i128).rem_euclid(Self::modulus() as i128) as u32,
+                    }
+                }
+            }
+        )*
+    };
+}
+dynamic_modint_new_from_signed!(i8, i16, i32, i64, i128, isize);
+macro_rules! dynamic_modint_new_from_unsigned {
+    ($($t:ty), *) => {
+        $(
+            impl<const ID: usize> From<$t> for DynamicModInt<ID> {
+                fn from(x: $t) -> Self {
+                    Self {
+                        value: (x as u128 % Self::modulus() as u128) as u32,
+                    }
+                }
+            }
+        )*
+    };
+}
+dynamic_modint_new_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl<const ID: usize> Debug for DynamicModInt<ID> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+impl<const ID: usize> Display for DynamicModInt<ID> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const ID: usize> Neg for DynamicModInt<ID> {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        if self.value > 0 {
+            self.value = Self::modulus() - self.value;
+        }
+        self
+    }
+}
+impl<const ID: usize> Zero for DynamicModInt<ID> {
+    fn zero() -> Self {
+        Self { value: 0 }
+    }
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+impl<const ID: usize> One for DynamicModInt<ID> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+}
+impl<const ID: usize> Group for DynamicModInt<ID> {}
+impl<const ID: usize> Abelian for DynamicModInt<ID> {}
+impl<const ID: usize> Ring for DynamicModInt<ID> {}
+impl<const ID: usize> Euclidean for DynamicModInt<ID> {
+    fn quotient(&self, rhs: &Self) -> Self {
+        Self::new((self.value / rhs.value) as u64)
+    }
+}
+impl<const ID: usize> Field for DynamicModInt<ID> {}
+
+impl<const ID: usize> AddAssign<DynamicModInt<ID>> for DynamicModInt<ID> {
+    fn add_assign(&mut self, rhs: DynamicModInt<ID>) {
+        let m = Self::modulus();
+        let (v, carry) = self.value.overflowing_add(rhs.value);
+        self.value = if carry || v >= m {
+            v.wrapping_sub(m)
+        } else {
+            v
+        };
+    }
+}
+impl<const ID: usize> SubAssign<DynamicModInt<ID>> for DynamicModInt<ID> {
+    fn sub_assign(&mut self, rhs: DynamicModInt<ID>) {
+        let (v, borrow) = self.value.overflowing_sub(rhs.value);
+        self.value = if borrow {
+            v.wrapping_add(Self::modulus())
+        } else {
+            v
+        };
+    }
+}
+impl<const ID: usize> MulAssign<DynamicModInt<ID>> for DynamicModInt<ID> {
+    fn mul_assign(&mut self, rhs: DynamicModInt<ID>) {
+        self.value = Self::reduce(self.value as u64 * rhs.value as u64);
+    }
+}
+impl<const ID: usize> DivAssign<DynamicModInt<ID>> for DynamicModInt<ID> {
+    fn div_assign(&mut self, rhs: DynamicModInt<ID>) {
+        self.mul_assign(rhs.inv().unwrap());
+    }
+}
+macro_rules! dynamic_modint_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+    )*) => {$(
+        impl<const ID: usize> $trait_assign<&DynamicModInt<ID>> for DynamicModInt<ID> {
+            fn $fn_assign(&mut self, rhs: &DynamicModInt<ID>) {
+                self.$fn_assign(*rhs);
+            }
+        }
+        impl<const ID: usize, T: Into<DynamicModInt<ID>>> $trait<T> for DynamicModInt<ID> {
+            type Output = DynamicModInt<ID>;
+            fn $fn(mut self, rhs: T) -> Self::Output {
+                self.$fn_assign(rhs.into());
+                self
+            }
+        }
+        impl<const ID: usize> $trait<&DynamicModInt<ID>> for DynamicModInt<ID> {
+            type Output = DynamicModInt<ID>;
+            fn $fn(self, rhs: &DynamicModInt<ID>) -> Self::Output {
+                self.$fn(*rhs)
+            }
+        }
+        impl<const ID: usize, T: Into<DynamicModInt<ID>>> $trait<T> for &DynamicModInt<ID> {
+            type Output = DynamicModInt<ID>;
+            fn $fn(self, rhs: T) -> Self::Output {
+                (*self).$fn(rhs.into())
+            }
+        }
+        impl<const ID: usize> $trait<&DynamicModInt<ID>> for &DynamicModInt<ID> {
+            type Output = DynamicModInt<ID>;
+            fn $fn(self, rhs: &DynamicModInt<ID>) -> Self::Output {
+                (*self).$fn(*rhs)
+            }
+        }
+    )*};
+}
+dynamic_modint_ops! {
+    Add, AddAssign, add, add_assign,
+    Sub, SubAssign, sub, sub_assign,
+    Mul, MulAssign, mul, mul_assign,
+    Div, DivAssign, div, div_assign,
+}
+
+impl<const ID: usize> Sum for DynamicModInt<ID> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0), |acc, a| acc + a)
+    }
+}
+impl<'a, const ID: usize> Sum<&'a Self> for DynamicModInt<ID> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+impl<const ID: usize> Product for DynamicModInt<ID> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), |acc, a| acc * a)
+    }
+}
+impl<'a, const ID: usize> Product<&'a Self> for DynamicModInt<ID> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynamicModInt;
+
+    #[test]
+    fn barrett_reduction() {
+        type Mint = DynamicModInt<7>;
+        for m in [1, 2, 3, 6, 998_244_353, u32::MAX] {
+            Mint::set_modulus(m);
+            let m = m as u64;
+            let values = (0..64)
+                .chain((m.saturating_sub(64)..m).rev())
+                .chain((0..64).map(|i| m / 2 + i))
+                .filter(|&v| v < m);
+            let values = values.collect::<Vec<_>>();
+            for &a in &values {
+                for &b in &values {
+                    assert_eq!(Mint::reduce(a * b) as u64, a * b % m);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn division() {
+        type Mint = DynamicModInt<6>;
+        Mint::set_modulus(6);
+        assert_eq!(Mint::new(2).inv(), None);
+        assert_eq!(Mint::new(5).inv(), Some(Mint::new(5)));
+        Mint::set_modulus(998_244_353);
+        for a in 1..100u64 {
+            let a = Mint::new(a * 0x9e37_79b9);
+            assert_eq!(a * a.inv().unwrap(), Mint::new(1));
+            assert_eq!(Mint::new(12_345) / a * a, Mint::new(12_345));
+        }
+        assert_eq!(Mint::new(0).inv(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_non_unit() {
+        type Mint = DynamicModInt<5>;
+        Mint::set_modulus(6);
+        let _ = Mint::new(1) / Mint::new(3);
+    }
+}
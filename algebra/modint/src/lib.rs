@@ -1,9 +1,16 @@
-use algebra::{Abelian, One, Ring, Zero};
+pub mod dynamic_modint;
+pub mod montgomery;
+
+use algebra::{Abelian, Euclidean, Exponent, Field, Group, One, Ring, Zero};
+use integer::{
+    prime::is_prime,
+    residue::{discrete_log, kth_root_mod, multiplicative_order, primitive_root, sqrt_mod},
+};
 
 use std::{
     fmt::{Debug, Display},
     iter::{Product, Sum},
-    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -16,9 +23,13 @@ impl<const MOD: u64> ModInt<MOD> {
         Self { value: value % MOD }
     }
 
-    pub fn pow(&self, mut exp: u32) -> Self {
+    pub fn pow<E: Exponent>(&self, exp: E) -> Self {
+        let mut exp = exp.to_u128();
+        if const { is_prime(MOD) } && self.value != 0 {
+            exp %= (MOD - 1) as u128;
+        }
         let mut res = Self::new(1);
-        let mut base = self.clone();
+        let mut base = *self;
         while exp > 0 {
             if exp & 1 == 1 {
                 res *= base;
@@ -28,6 +39,50 @@ impl<const MOD: u64> ModInt<MOD> {
         }
         res
     }
+
+    pub fn pow_decimal(&self, exp: &str) -> Self {
+        exp.bytes()
+            .fold(Self::one(), |acc, d| acc.pow(10u32) * self.pow(d - b'0'))
+    }
+
+    pub fn inv(&self) -> Option<Self> {
+        let (mut a, mut b) = (self.value as i128, MOD as i128);
+        let (mut x, mut y) = (1i128, 0i128);
+        while b > 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        if a == 1 {
+            Some(Self::new(x.rem_euclid(MOD as i128) as u64))
+        } else {
+            None
+        }
+    }
+
+    pub fn sqrt(&self) -> Option<Self> {
+        const { assert!(is_prime(MOD)) };
+        sqrt_mod(self.value, MOD).map(Self::new)
+    }
+
+    pub fn kth_root(&self, k: u64) -> Option<Self> {
+        const { assert!(is_prime(MOD)) };
+        kth_root_mod(self.value, k, MOD).map(Self::new)
+    }
+
+    pub fn log(&self, base: Self) -> Option<u64> {
+        discrete_log(base.value, self.value, MOD)
+    }
+
+    pub fn order(&self) -> Option<u64> {
+        const { assert!(is_prime(MOD)) };
+        multiplicative_order(self.value, MOD)
+    }
+
+    pub fn primitive_root() -> Self {
+        const { assert!(is_prime(MOD)) };
+        Self::new(primitive_root(MOD))
+    }
 }
 
 #[macro_export]
@@ -107,8 +162,18 @@ impl<const MOD: u64> One for ModInt<MOD> {
         }
     }
 }
+impl<const MOD: u64> Group for ModInt<MOD> {}
 impl<const MOD: u64> Abelian for ModInt<MOD> {}
 impl<const MOD: u64> Ring for ModInt<MOD> {}
+impl<const MOD: u64> Euclidean for ModInt<MOD> {
+    fn quotient(&self, rhs: &Self) -> Self {
+        Self::new(self.value / rhs.value)
+    }
+}
+// Field holds only for prime MOD. Stable Rust cannot bound an impl on a const
+// predicate, so a composite MOD is rejected at compile time when division is
+// instantiated.
+impl<const MOD: u64> Field for ModInt<MOD> {}
 
 impl<const MOD: u64> AddAssign<ModInt<MOD>> for ModInt<MOD> {
     fn add_assign(&mut self, rhs: ModInt<MOD>) {
@@ -131,6 +196,12 @@ impl<const MOD: u64> MulAssign<ModInt<MOD>> for ModInt<MOD> {
         self.value = (self.value as u128 * rhs.value as u128 % MOD as u128) as u64;
     }
 }
+impl<const MOD: u64> DivAssign<ModInt<MOD>> for ModInt<MOD> {
+    fn div_assign(&mut self, rhs: ModInt<MOD>) {
+        const { assert!(is_prime(MOD), "division requires a prime modulus") };
+        self.mul_assign(rhs.inv().unwrap());
+    }
+}
 macro_rules! modint_ops {
     ($(
             $trait:ident,
@@ -174,6 +245,7 @@ modint_ops! {
     Add, AddAssign, add, add_assign,
     Sub, SubAssign, sub, sub_assign,
     Mul, MulAssign, mul, mul_assign,
+    Div, DivAssign, div, div_assign,
 }
 
 impl<const MOD: u64> Sum for ModInt<MOD> {
@@ -196,3 +268,26 @@ impl<'a, const MOD: u64> Product<&'a Self> for ModInt<MOD> {
         iter.copied().product()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ModInt;
+
+    #[test]
+    fn inverse_and_division() {
+        type Composite = ModInt<{ 1u64 << 40 }>;
+        assert_eq!(Composite::new(6).inv(), None);
+        assert_eq!(
+            Composite::new(3).inv().unwrap() * Composite::new(3),
+            Composite::new(1)
+        );
+        assert_eq!(ModInt::<7>::new(0).inv(), None);
+
+        type Mint = ModInt<{ (1 << 61) - 1 }>;
+        for a in 1..200u64 {
+            let a = Mint::new(a.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+            assert_eq!(a * a.inv().unwrap(), Mint::new(1));
+            assert_eq!(Mint::new(12_345) / a * a, Mint::new(12_345));
+        }
+    }
+}
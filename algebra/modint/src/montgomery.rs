@@ -0,0 +1,295 @@
+use algebra::{Abelian, Euclidean, Exponent, Field, Group, One, Ring, Zero};
+use integer::prime::is_prime;
+
+use std::{
+    fmt::{Debug, Display},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MontgomeryModInt<const MOD: u64> {
+    value: u64,
+}
+
+impl<const MOD: u64> MontgomeryModInt<MOD> {
+    const MOD_INV: u64 = {
+        assert!(MOD & 1 == 1);
+        let mut inv = MOD;
+        let mut i = 0;
+        while i < 5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(MOD.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv
+    };
+    const R2: u64 = ((u128::MAX % MOD as u128 + 1) % MOD as u128) as u64;
+
+    pub fn new(value: u64) -> Self {
+        Self {
+            value: Self::reduce((value % MOD) as u128 * Self::R2 as u128),
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        Self::reduce(self.value as u128)
+    }
+
+    pub fn pow<E: Exponent>(&self, exp: E) -> Self {
+        let mut exp = exp.to_u128();
+        if const { is_prime(MOD) } && self.value != 0 {
+            exp %= (MOD - 1) as u128;
+        }
+        let mut res = Self::new(1);
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        res
+    }
+
+    pub fn pow_decimal(&self, exp: &str) -> Self {
+        exp.bytes()
+            .fold(Self::one(), |acc, d| acc.pow(10u32) * self.pow(d - b'0'))
+    }
+
+    pub fn inv(&self) -> Option<Self> {
+        let (mut a, mut b) = (self.value() as i128, MOD as i128);
+        let (mut x, mut y) = (1i128, 0i128);
+        while b > 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        if a == 1 {
+            Some(Self::new(x.rem_euclid(MOD as i128) as u64))
+        } else {
+            None
+        }
+    }
+
+    fn reduce(t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(Self::MOD_INV);
+        let mn = m as u128 * MOD as u128;
+        let (v, borrow) = ((t >> 64) as u64).overflowing_sub((mn >> 64) as u64);
+        if borrow {
+            v.wrapping_add(MOD)
+        } else {
+            v
+        }
+    }
+}
+
+macro_rules! montgomery_modint_new_from_signed {
+    ($($t:ty), *) => {
+        $(
+            impl<const MOD: u64> From<$t> for MontgomeryModInt<MOD> {
+                fn from(x: $t) -> Self {
+                    Self::new((x as i128).rem_euclid(MOD as i128) as u64)
+                }
+            }
+        )*
+    };
+}
+montgomery_modint_new_from_signed!(i8, i16, i32, i64, i128, isize);
+macro_rules! montgomery_modint_new_from_unsigned {
+    ($($t:ty), *) => {
+        $(
+            impl<const MOD: u64> From<$t> for MontgomeryModInt<MOD> {
+                fn from(x: $t) -> Self {
+                    Self::new((x as u128 % MOD as u128) as u64)
+                }
+            }
+        )*
+    };
+}
+montgomery_modint_new_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl<const MOD: u64> Debug for MontgomeryModInt<MOD> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+impl<const MOD: u64> Display for MontgomeryModInt<MOD> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<const MOD: u64> Neg for MontgomeryModInt<MOD> {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        if self.value > 0 {
+            self.value = MOD - self.value;
+        }
+        self
+    }
+}
+impl<const MOD: u64> Zero for MontgomeryModInt<MOD> {
+    fn zero() -> Self {
+        Self { value: 0 }
+    }
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+impl<const MOD: u64> One for MontgomeryModInt<MOD> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+}
+impl<const MOD: u64> Group for MontgomeryModInt<MOD> {}
+impl<const MOD: u64> Abelian for MontgomeryModInt<MOD> {}
+impl<const MOD: u64> Ring for MontgomeryModInt<MOD> {}
+impl<const MOD: u64> Euclidean for MontgomeryModInt<MOD> {
+    fn quotient(&self, rhs: &Self) -> Self {
+        Self::new(self.value() / rhs.value())
+    }
+}
+// Prime MOD only, see `Field for ModInt`.
+impl<const MOD: u64> Field for MontgomeryModInt<MOD> {}
+
+impl<const MOD: u64> AddAssign<MontgomeryModInt<MOD>> for MontgomeryModInt<MOD> {
+    fn add_assign(&mut self, rhs: MontgomeryModInt<MOD>) {
+        let (v, carry) = self.value.overflowing_add(rhs.value);
+        self.value = if carry || v >= MOD {
+            v.wrapping_sub(MOD)
+        } else {
+            v
+        };
+    }
+}
+impl<const MOD: u64> SubAssign<MontgomeryModInt<MOD>> for MontgomeryModInt<MOD> {
+    fn sub_assign(&mut self, rhs: MontgomeryModInt<MOD>) {
+        let (v, borrow) = self.value.overflowing_sub(rhs.value);
+        self.value = if borrow { v.wrapping_add(MOD) } else { v };
+    }
+}
+impl<const MOD: u64> MulAssign<MontgomeryModInt<MOD>> for MontgomeryModInt<MOD> {
+    fn mul_assign(&mut self, rhs: MontgomeryModInt<MOD>) {
+        self.value = Self::reduce(self.value as u128 * rhs.value as u128);
+    }
+}
+impl<const MOD: u64> DivAssign<MontgomeryModInt<MOD>> for MontgomeryModInt<MOD> {
+    fn div_assign(&mut self, rhs: MontgomeryModInt<MOD>) {
+        const { assert!(is_prime(MOD), "division requires a prime modulus") };
+        self.mul_assign(rhs.inv().unwrap());
+    }
+}
+macro_rules! montgomery_modint_ops {
+    ($(
+            $trait:ident,
+            $trait_assign:ident,
+            $fn:ident,
+            $fn_assign:ident,
+    )*) => {$(
+        impl<const MOD: u64> $trait_assign<&MontgomeryModInt<MOD>> for MontgomeryModInt<MOD> {
+            fn $fn_assign(&mut self, rhs: &MontgomeryModInt<MOD>) {
+                self.$fn_assign(*rhs);
+            }
+        }
+        impl<const MOD: u64, T: Into<MontgomeryModInt<MOD>>> $trait<T> for MontgomeryModInt<MOD> {
+            type Output = MontgomeryModInt<MOD>;
+            fn $fn(mut self, rhs: T) -> Self::Output {
+                self.$fn_assign(rhs.into());
+                self
+            }
+        }
+        impl<const MOD: u64> $trait<&MontgomeryModInt<MOD>> for MontgomeryModInt<MOD> {
+            type Output = MontgomeryModInt<MOD>;
+            fn $fn(self, rhs: &MontgomeryModInt<MOD>) -> Self::Output {
+                self.$fn(*rhs)
+            }
+        }
+        impl<const MOD: u64, T: Into<MontgomeryModInt<MOD>>> $trait<T> for &MontgomeryModInt<MOD> {
+            type Output = MontgomeryModInt<MOD>;
+            fn $fn(self, rhs: T) -> Self::Output {
+                (*self).$fn(rhs.into())
+            }
+        }
+        impl<const MOD: u64> $trait<&MontgomeryModInt<MOD>> for &MontgomeryModInt<MOD> {
+            type Output = MontgomeryModInt<MOD>;
+            fn $fn(self, rhs: &MontgomeryModInt<MOD>) -> Self::Output {
+                (*self).$fn(*rhs)
+            }
+        }
+    )*};
+}
+montgomery_modint_ops! {
+    Add, AddAssign, add, add_assign,
+    Sub, SubAssign, sub, sub_assign,
+    Mul, MulAssign, mul, mul_assign,
+    Div, DivAssign, div, div_assign,
+}
+
+impl<const MOD: u64> Sum for MontgomeryModInt<MOD> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, a| acc + a)
+    }
+}
+impl<'a, const MOD: u64> Sum<&'a Self> for MontgomeryModInt<MOD> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+impl<const MOD: u64> Product for MontgomeryModInt<MOD> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, a| acc * a)
+    }
+}
+impl<'a, const MOD: u64> Product<&'a Self> for MontgomeryModInt<MOD> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MontgomeryModInt;
+    use crate::ModInt;
+    use xorshift::XorShift;
+
+    fn check<const MOD: u64>() {
+        let mut rng = XorShift::default();
+        let mut values = vec![0, 1 % MOD, 2 % MOD, MOD - 1, MOD / 2];
+        values.extend((0..200).map(|_| rng.below(MOD)));
+        for &a in &values {
+            let (x, y) = (ModInt::<MOD>::new(a), MontgomeryModInt::<MOD>::new(a));
+            assert_eq!(y.value(), a);
+            assert_eq!((-y).to_string(), (-x).to_string());
+            assert_eq!(format!("{:?}", y.inv()), format!("{:?}", x.inv()));
+            let e = rng.next_u64();
+            assert_eq!(y.pow(e).to_string(), x.pow(e).to_string());
+            for &b in values.iter().take(20) {
+                let (u, v) = (ModInt::<MOD>::new(b), MontgomeryModInt::<MOD>::new(b));
+                assert_eq!((y + v).to_string(), (x + u).to_string());
+                assert_eq!((y - v).to_string(), (x - u).to_string());
+                assert_eq!((y * v).to_string(), (x * u).to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn matches_modint() {
+        check::<998_244_353>();
+        check::<1_000_000_007>();
+        check::<{ (1 << 61) - 1 }>();
+        check::<{ 3 * 5 * 7 * 11 * 13 * 1_000_003 }>();
+        check::<1>();
+    }
+
+    #[test]
+    fn division() {
+        type Mint = MontgomeryModInt<{ (1 << 61) - 1 }>;
+        let a = Mint::new(0x9e37_79b9_7f4a_7c15);
+        assert_eq!(a / a, Mint::new(1));
+        assert_eq!(Mint::new(6) / Mint::new(3), Mint::new(2));
+        assert_eq!(Mint::new(0).inv(), None);
+    }
+}
@@ -0,0 +1,81 @@
+use super::erathosthenes::SieveOfEratosthenesMini;
+
+use algebra::{Abelian, Zero};
+
+fn primes(n: usize) -> impl Iterator<Item = usize> {
+    SieveOfEratosthenesMini::new(n).primes().into_iter()
+}
+
+pub fn divisor_zeta<T: Zero + Copy>(a: &mut [T]) {
+    let n = a.len();
+    for p in primes(n) {
+        for i in 1..=(n - 1) / p {
+            a[i * p] = a[i * p] + a[i];
+        }
+    }
+}
+
+pub fn divisor_mobius<T: Abelian + Copy>(a: &mut [T]) {
+    let n = a.len();
+    for p in primes(n) {
+        for i in (1..=(n - 1) / p).rev() {
+            a[i * p] = a[i * p] - a[i];
+        }
+    }
+}
+
+pub fn multiple_zeta<T: Zero + Copy>(a: &mut [T]) {
+    let n = a.len();
+    for p in primes(n) {
+        for i in (1..=(n - 1) / p).rev() {
+            a[i] = a[i] + a[i * p];
+        }
+    }
+}
+
+pub fn multiple_mobius<T: Abelian + Copy>(a: &mut [T]) {
+    let n = a.len();
+    for p in primes(n) {
+        for i in 1..=(n - 1) / p {
+            a[i] = a[i] - a[i * p];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{divisor_mobius, divisor_zeta, multiple_mobius, multiple_zeta};
+
+    #[test]
+    fn transforms_match_brute_force() {
+        for n in 0..200usize {
+            let a = (0..n as i64)
+                .map(|i| (i * 37 + 11) % 23 - 11)
+                .collect::<Vec<_>>();
+            let divisor = (0..n)
+                .map(|i| match i {
+                    0 => a[0],
+                    _ => (1..=i).filter(|d| i.is_multiple_of(*d)).map(|d| a[d]).sum(),
+                })
+                .collect::<Vec<_>>();
+            let multiple = (0..n)
+                .map(|i| match i {
+                    0 => a[0],
+                    _ => (i..n).step_by(i).map(|j| a[j]).sum(),
+                })
+                .collect::<Vec<_>>();
+
+            let mut b = a.clone();
+            divisor_zeta(&mut b);
+            assert_eq!(b, divisor);
+            divisor_mobius(&mut b);
+            assert_eq!(b, a);
+
+            let mut b = a.clone();
+            multiple_zeta(&mut b);
+            assert_eq!(b, multiple);
+            multiple_mobius(&mut b);
+            assert_eq!(b, a);
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use integer::factorization::Factorization;
+
+use std::ops::{Bound, RangeBounds};
+
 pub struct SieveOfEratosthenes {
     min_factor: Vec<usize>,
 }
@@ -25,10 +29,11 @@ impl SieveOfEratosthenes {
         (0..n).filter(|i| self.is_prime(*i)).collect::<Vec<_>>()
     }
 
-    pub fn factorize(&self, mut n: usize) -> Vec<(usize, usize)> {
-        if n == 1 {
-            return vec![(1, 1)];
-        }
+    /// # Panics
+    ///
+    /// Panics if `n == 0` or `n` is not below the sieve bound.
+    pub fn factorize(&self, mut n: usize) -> Factorization {
+        assert!(n > 0, "cannot factorize 0");
         let mut res = vec![];
         while n > 1 {
             let p = self.min_factor[n];
@@ -37,68 +42,231 @@ impl SieveOfEratosthenes {
                 n /= p;
                 cnt += 1;
             }
-            res.push((p, cnt));
+            res.push((p as u64, cnt));
         }
-        res
+        res.into_iter().collect()
     }
 
     pub fn divisors(&self, n: usize) -> Vec<usize> {
-        if n == 1 {
-            return vec![1];
-        }
-        let factorize = self.factorize(n);
-        let mut res = vec![1];
-        for &(p, cnt) in &factorize {
-            for i in 0..res.len() {
-                let mut tmp = 1;
-                for _ in 0..cnt {
-                    tmp *= p;
-                    res.push(res[i] * tmp);
-                }
-            }
-        }
-        res
+        self.factorize(n).divisors().map(|d| d as usize).collect()
     }
 }
 
 pub struct SieveOfEratosthenesMini {
+    n: usize,
     is_prime: Vec<u64>,
 }
 
 impl SieveOfEratosthenesMini {
     pub fn new(n: usize) -> Self {
-        let mut is_prime = vec![!0; (n + 63) / 64];
-        is_prime[0] = !0 ^ 1 ^ 2;
-        for i in n % 64..64 {
-            is_prime[(n + 63) / 64 - 1] ^= 1 << i;
+        let m = n / 2;
+        let mut is_prime = vec![!0u64; m.div_ceil(64)];
+        if !m.is_multiple_of(64) {
+            *is_prime.last_mut().unwrap() = (1 << (m % 64)) - 1;
         }
-        for i in 2..n {
-            if (is_prime[i / 64] >> (i % 64)) & 1 == 1 {
-                for j in (2..).take_while(|j| i * j < n) {
-                    if (is_prime[i * j / 64] >> (i * j % 64)) & 1 == 1 {
-                        is_prime[i * j / 64] = is_prime[i * j / 64] ^ (1 << (i * j % 64));
-                    }
+        if m > 0 {
+            is_prime[0] ^= 1;
+        }
+
+        let mut sqrt = 1;
+        while (sqrt + 1) * (sqrt + 1) < n {
+            sqrt += 1;
+        }
+        let mut small = vec![true; sqrt + 1];
+        let mut primes = vec![];
+        for p in 3..=sqrt {
+            if small[p] && p % 2 == 1 {
+                primes.push(p);
+                for q in (p * p..=sqrt).step_by(p) {
+                    small[q] = false;
                 }
             }
         }
-        Self { is_prime }
+
+        const BLOCK: usize = 1 << 18;
+        let mut next = primes.iter().map(|&p| p * p / 2).collect::<Vec<_>>();
+        for low in (0..m).step_by(BLOCK) {
+            let high = (low + BLOCK).min(m);
+            for (&p, next) in primes.iter().zip(next.iter_mut()) {
+                if *next >= high {
+                    continue;
+                }
+                let mut j = *next;
+                while j < high {
+                    is_prime[j / 64] &= !(1 << (j % 64));
+                    j += p;
+                }
+                *next = j;
+            }
+        }
+        Self { n, is_prime }
     }
 
     pub fn is_prime(&self, n: usize) -> bool {
-        (self.is_prime[n / 64] >> (n % 64)) & 1 == 1
+        assert!(n < self.n);
+        if n.is_multiple_of(2) {
+            return n == 2;
+        }
+        (self.is_prime[n / 128] >> (n / 2 % 64)) & 1 == 1
     }
 
     pub fn primes(&self) -> Vec<usize> {
-        let mut res = vec![];
-        for (i, &x) in self.is_prime.iter().enumerate() {
-            if x != 0 {
-                for j in 0..64 {
-                    if (x >> j) & 1 == 1 {
-                        res.push(i * 64 + j);
+        self.primes_in(..).collect()
+    }
+
+    pub fn primes_in<R: RangeBounds<usize>>(&self, range: R) -> impl Iterator<Item = usize> + '_ {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e + 1,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.n,
+        }
+        .min(self.n);
+        let start = start.min(end);
+        let two = (start <= 2 && 2 < end).then_some(2);
+        let (low, high) = (start / 2, end / 2);
+        let odd = (low / 64..high.div_ceil(64)).flat_map(move |w| {
+            let mut bits = self.is_prime[w];
+            if w == low / 64 {
+                bits &= !0 << (low % 64);
+            }
+            if w == high / 64 {
+                bits &= (1 << (high % 64)) - 1;
+            }
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let i = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                Some(2 * (w * 64 + i) + 1)
+            })
+        });
+        two.into_iter().chain(odd)
+    }
+
+    /// Returns the number of primes `p <= n`.
+    pub fn count_primes(&self, n: usize) -> usize {
+        assert!(n < self.n);
+        let m = n.div_ceil(2);
+        let mut res = self.is_prime[..m / 64]
+            .iter()
+            .map(|x| x.count_ones() as usize)
+            .sum::<usize>();
+        if !m.is_multiple_of(64) {
+            res += (self.is_prime[m / 64] & ((1 << (m % 64)) - 1)).count_ones() as usize;
+        }
+        if n >= 2 {
+            res += 1;
+        }
+        res
+    }
+
+    /// Returns the `k`-th prime below the sieve bound, counting from 0, so
+    /// `nth_prime(0) == Some(2)`.
+    pub fn nth_prime(&self, mut k: usize) -> Option<usize> {
+        if self.n <= 2 {
+            return None;
+        }
+        if k == 0 {
+            return Some(2);
+        }
+        k -= 1;
+        for (w, &x) in self.is_prime.iter().enumerate() {
+            let c = x.count_ones() as usize;
+            if k < c {
+                let mut x = x;
+                for _ in 0..k {
+                    x &= x - 1;
+                }
+                return Some(2 * (w * 64 + x.trailing_zeros() as usize) + 1);
+            }
+            k -= c;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SieveOfEratosthenes, SieveOfEratosthenesMini};
+
+    use integer::factorization::Factorization;
+
+    use std::ops::Bound;
+
+    #[test]
+    fn factorize_matches_integer() {
+        let sieve = SieveOfEratosthenes::new(5000);
+        assert!(sieve.factorize(1).prime_powers().is_empty());
+        for n in 1..5000 {
+            assert_eq!(sieve.factorize(n), Factorization::new(n as u64));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot factorize 0")]
+    fn factorize_zero_panics() {
+        SieveOfEratosthenes::new(10).factorize(0);
+    }
+
+    #[test]
+    fn matches_naive_sieve() {
+        for n in (0..300).chain([1_000, 4_096, 4_097, 100_000, 600_001]) {
+            let mut is_prime = vec![true; n];
+            for i in 0..n {
+                if i < 2 {
+                    is_prime[i] = false;
+                } else if is_prime[i] {
+                    for j in (i * i..n).step_by(i) {
+                        is_prime[j] = false;
                     }
                 }
             }
+            let primes = (0..n).filter(|&i| is_prime[i]).collect::<Vec<_>>();
+            let sieve = SieveOfEratosthenesMini::new(n);
+            assert_eq!(sieve.primes(), primes);
+            for i in (0..n).step_by(n / 97 + 1) {
+                assert_eq!(sieve.is_prime(i), is_prime[i]);
+                assert_eq!(
+                    sieve.count_primes(i),
+                    primes.iter().take_while(|&&p| p <= i).count()
+                );
+            }
+            for (k, &p) in primes.iter().enumerate() {
+                assert_eq!(sieve.nth_prime(k), Some(p));
+            }
+            assert_eq!(sieve.nth_prime(primes.len()), None);
+
+            let between = |lo: usize, hi: usize| {
+                primes
+                    .iter()
+                    .copied()
+                    .filter(|&p| lo <= p && p < hi)
+                    .collect::<Vec<_>>()
+            };
+            let bounds = [0, 1, 2, 3, n / 3, n / 2 + 1, n.saturating_sub(1), n, n + 5];
+            for &l in &bounds {
+                for &r in &bounds {
+                    let excluded = (Bound::Excluded(l), Bound::Excluded(r));
+                    assert_eq!(sieve.primes_in(l..r).collect::<Vec<_>>(), between(l, r));
+                    assert_eq!(
+                        sieve.primes_in(l..=r).collect::<Vec<_>>(),
+                        between(l, r + 1)
+                    );
+                    assert_eq!(
+                        sieve.primes_in(excluded).collect::<Vec<_>>(),
+                        between(l + 1, r)
+                    );
+                }
+                assert_eq!(sieve.primes_in(l..).collect::<Vec<_>>(), between(l, n));
+                assert_eq!(sieve.primes_in(..l).collect::<Vec<_>>(), between(0, l));
+                assert_eq!(sieve.primes_in(..=l).collect::<Vec<_>>(), between(0, l + 1));
+            }
         }
-        res
     }
 }
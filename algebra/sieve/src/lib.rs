@@ -1 +1,4 @@
+pub mod divisor_transform;
 pub mod erathosthenes;
+pub mod linear;
+pub mod segmented;
@@ -0,0 +1,144 @@
+pub struct LinearSieve {
+    primes: Vec<usize>,
+    min_factor: Vec<usize>,
+    phi: Vec<usize>,
+    mobius: Vec<i8>,
+    divisor_count: Vec<usize>,
+    divisor_sum: Vec<usize>,
+}
+
+impl LinearSieve {
+    pub fn new(n: usize) -> Self {
+        let n = n.max(2);
+        let mut primes = vec![];
+        let mut min_factor = vec![0; n];
+        let mut phi = vec![0; n];
+        let mut mobius = vec![0; n];
+        let mut divisor_count = vec![0; n];
+        let mut divisor_sum = vec![0; n];
+        let mut exponent = vec![0; n];
+        let mut prime_power = vec![0; n];
+        let mut prime_power_sum = vec![0; n];
+        min_factor[1] = 1;
+        phi[1] = 1;
+        mobius[1] = 1;
+        divisor_count[1] = 1;
+        divisor_sum[1] = 1;
+        for i in 2..n {
+            if min_factor[i] == 0 {
+                min_factor[i] = i;
+                primes.push(i);
+                phi[i] = i - 1;
+                mobius[i] = -1;
+                divisor_count[i] = 2;
+                divisor_sum[i] = i + 1;
+                exponent[i] = 1;
+                prime_power[i] = i;
+                prime_power_sum[i] = i + 1;
+            }
+            for &p in &primes {
+                if p > min_factor[i] || i * p >= n {
+                    break;
+                }
+                let j = i * p;
+                min_factor[j] = p;
+                if p == min_factor[i] {
+                    phi[j] = phi[i] * p;
+                    exponent[j] = exponent[i] + 1;
+                    divisor_count[j] = divisor_count[i] / (exponent[i] + 1) * (exponent[j] + 1);
+                    prime_power[j] = prime_power[i] * p;
+                    prime_power_sum[j] = prime_power_sum[i] + prime_power[j];
+                    divisor_sum[j] = divisor_sum[i] / prime_power_sum[i] * prime_power_sum[j];
+                } else {
+                    phi[j] = phi[i] * (p - 1);
+                    mobius[j] = -mobius[i];
+                    exponent[j] = 1;
+                    divisor_count[j] = divisor_count[i] * 2;
+                    prime_power[j] = p;
+                    prime_power_sum[j] = p + 1;
+                    divisor_sum[j] = divisor_sum[i] * (p + 1);
+                }
+            }
+        }
+        Self {
+            primes,
+            min_factor,
+            phi,
+            mobius,
+            divisor_count,
+            divisor_sum,
+        }
+    }
+
+    pub fn is_prime(&self, n: usize) -> bool {
+        n >= 2 && self.min_factor[n] == n
+    }
+
+    pub fn primes(&self) -> &[usize] {
+        &self.primes
+    }
+
+    pub fn min_factor(&self, n: usize) -> usize {
+        self.min_factor[n]
+    }
+
+    pub fn phi(&self, n: usize) -> usize {
+        self.phi[n]
+    }
+
+    pub fn mobius(&self, n: usize) -> i8 {
+        self.mobius[n]
+    }
+
+    pub fn divisor_count(&self, n: usize) -> usize {
+        self.divisor_count[n]
+    }
+
+    pub fn divisor_sum(&self, n: usize) -> usize {
+        self.divisor_sum[n]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearSieve;
+
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        const N: usize = 2000;
+        let sieve = LinearSieve::new(N);
+        for n in 1..N {
+            let divisors = (1..=n).filter(|d| n.is_multiple_of(*d)).collect::<Vec<_>>();
+            let prime_factors = divisors[1..]
+                .iter()
+                .filter(|&&d| (2..d).all(|q| !d.is_multiple_of(q)))
+                .collect::<Vec<_>>();
+            let square_free = divisors[1..]
+                .iter()
+                .all(|&d| d == 1 || !n.is_multiple_of(d * d));
+            let mobius = match (square_free, prime_factors.len() % 2) {
+                (false, _) => 0,
+                (true, 0) => 1,
+                (true, _) => -1,
+            };
+            assert_eq!(sieve.is_prime(n), divisors.len() == 2);
+            assert_eq!(sieve.min_factor(n), *divisors.get(1).unwrap_or(&1));
+            assert_eq!(sieve.phi(n), (1..=n).filter(|&k| gcd(n, k) == 1).count());
+            assert_eq!(sieve.mobius(n), mobius);
+            assert_eq!(sieve.divisor_count(n), divisors.len());
+            assert_eq!(sieve.divisor_sum(n), divisors.iter().sum());
+        }
+        assert_eq!(
+            sieve.primes(),
+            (2..N).filter(|&n| sieve.is_prime(n)).collect::<Vec<_>>()
+        );
+    }
+}
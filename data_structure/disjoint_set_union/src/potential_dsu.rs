@@ -1,17 +1,15 @@
-pub trait PotentialDSUOp {
-    type Value: Clone + PartialEq;
-    fn e() -> Self::Value;
-    fn mul(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
-    fn inv(val: &Self::Value) -> Self::Value;
-}
+use algebra::monoid::Group;
 
-pub struct PotentialDSU<T: PotentialDSUOp> {
+pub struct PotentialDSU<T: Group> {
     parents: Vec<i32>,
     potentials: Vec<T::Value>,
     cnt: usize,
 }
 
-impl<T: PotentialDSUOp> PotentialDSU<T> {
+impl<T: Group> PotentialDSU<T>
+where
+    T::Value: PartialEq,
+{
     pub fn new(n: usize) -> Self {
         Self {
             parents: vec![-1; n],
@@ -24,7 +22,7 @@ impl<T: PotentialDSUOp> PotentialDSU<T> {
         let mut potential = self.potentials[v].clone();
         while self.parents[v] >= 0 {
             v = self.parents[v] as usize;
-            potential = T::mul(&self.potentials[v], &potential);
+            potential = T::op(&self.potentials[v], &potential);
         }
         (v, potential)
     }
@@ -33,9 +31,9 @@ impl<T: PotentialDSUOp> PotentialDSU<T> {
         let (mut from, p_from) = self.root(from);
         let (mut to, p_to) = self.root(to);
         if from == to {
-            T::mul(&p_from, &d) == p_to
+            T::op(&p_from, &d) == p_to
         } else {
-            let mut d = T::mul(&T::mul(&p_from, &d), &T::inv(&p_to));
+            let mut d = T::op(&T::op(&p_from, &d), &T::inv(&p_to));
             if self.parents[from] > self.parents[to] {
                 std::mem::swap(&mut from, &mut to);
                 d = T::inv(&d);
@@ -52,7 +50,7 @@ impl<T: PotentialDSUOp> PotentialDSU<T> {
         let (from, p_from) = self.root(from);
         let (to, p_to) = self.root(to);
         if from == to {
-            Some(T::mul(&T::inv(&p_from), &p_to))
+            Some(T::op(&T::inv(&p_from), &p_to))
         } else {
             None
         }
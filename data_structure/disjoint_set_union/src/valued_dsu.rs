@@ -1,16 +1,12 @@
-pub trait ValuedDSUOp {
-    type Value: Copy;
-    fn e() -> Self::Value;
-    fn op(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
-}
+use algebra::monoid::CommutativeMonoid;
 
-pub struct ValuedDSU<T: ValuedDSUOp> {
+pub struct ValuedDSU<T: CommutativeMonoid> {
     parents: Vec<isize>,
     values: Vec<T::Value>,
     cnt: usize,
 }
 
-impl<T: ValuedDSUOp> ValuedDSU<T> {
+impl<T: CommutativeMonoid> ValuedDSU<T> {
     pub fn new(n: usize) -> Self {
         Self {
             parents: vec![-1; n],
@@ -27,7 +23,7 @@ impl<T: ValuedDSUOp> ValuedDSU<T> {
     }
 
     pub fn value(&self, v: usize) -> T::Value {
-        self.values[self.root(v)]
+        self.values[self.root(v)].clone()
     }
 
     pub fn update_at(&mut self, v: usize, value: T::Value) {
@@ -1,23 +1,19 @@
-pub trait FenwickTreeOp {
-    type Value: Clone;
-    fn e() -> Self::Value;
-    fn add(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
-}
+use algebra::monoid::CommutativeMonoid;
 
-pub struct FenwickTree<T: FenwickTreeOp> {
+pub struct FenwickTree<T: CommutativeMonoid> {
     values: Vec<T::Value>,
 }
 
-impl<T: FenwickTreeOp> FenwickTree<T> {
+impl<T: CommutativeMonoid> FenwickTree<T> {
     pub fn new(a: &[T::Value]) -> Self {
         let n = a.len();
         let mut values = vec![T::e(); n + 1];
         for (i, a) in a.iter().enumerate() {
             let i = i + 1;
-            values[i] = T::add(&values[i], &a);
+            values[i] = T::op(&values[i], &a);
             let lsb = i & i.wrapping_neg();
             if i + lsb < n + 1 {
-                values[i + lsb] = T::add(&values[i + lsb], &values[i]);
+                values[i + lsb] = T::op(&values[i + lsb], &values[i]);
             }
         }
         Self { values }
@@ -28,7 +24,7 @@ impl<T: FenwickTreeOp> FenwickTree<T> {
         let lsb = n & n.wrapping_neg();
         let mut d = 1;
         while d < lsb {
-            x = T::add(&x, &self.values[n - d]);
+            x = T::op(&x, &self.values[n - d]);
             d *= 2;
         }
         self.values.push(x);
@@ -37,7 +33,7 @@ impl<T: FenwickTreeOp> FenwickTree<T> {
     pub fn add_at(&mut self, mut i: usize, x: T::Value) {
         i += 1;
         while i < self.values.len() {
-            self.values[i] = T::add(&self.values[i], &x);
+            self.values[i] = T::op(&self.values[i], &x);
             i += i & i.wrapping_neg();
         }
     }
@@ -45,7 +41,7 @@ impl<T: FenwickTreeOp> FenwickTree<T> {
     pub fn fold(&self, mut r: usize) -> T::Value {
         let mut res = T::e();
         while r > 0 {
-            res = T::add(&res, &self.values[r]);
+            res = T::op(&res, &self.values[r]);
             r -= r & r.wrapping_neg();
         }
         res
@@ -1,29 +1,24 @@
-use std::ops::{Bound, RangeBounds};
+use algebra::monoid::AbelianGroup;
 
-pub trait InclusiveScan2dOp {
-    type Value: Copy;
-    fn e() -> Self::Value;
-    fn add(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
-    fn inv(val: &Self::Value) -> Self::Value;
-}
+use std::ops::{Bound, RangeBounds};
 
-pub struct InclusiveScan2d<T: InclusiveScan2dOp> {
+pub struct InclusiveScan2d<T: AbelianGroup> {
     value: Vec<Vec<T::Value>>,
 }
 
-impl<T: InclusiveScan2dOp> InclusiveScan2d<T> {
+impl<T: AbelianGroup> InclusiveScan2d<T> {
     pub fn new(a: &Vec<Vec<T::Value>>) -> Self {
         let h = a.len();
         let w = a[0].len();
         let mut value = vec![vec![T::e(); w + 1]; h + 1];
         for (i, a) in a.iter().enumerate() {
             for (j, a) in a.iter().enumerate() {
-                value[i + 1][j + 1] = T::add(&value[i + 1][j], a);
+                value[i + 1][j + 1] = T::op(&value[i + 1][j], a);
             }
         }
         for j in 0..w {
             for i in 0..h {
-                value[i + 1][j + 1] = T::add(&value[i + 1][j + 1], &value[i][j + 1]);
+                value[i + 1][j + 1] = T::op(&value[i + 1][j + 1], &value[i][j + 1]);
             }
         }
         Self { value }
@@ -34,9 +29,9 @@ impl<T: InclusiveScan2dOp> InclusiveScan2d<T> {
         let w = self.value[0].len();
         let (xl, xr) = unzip(x, h);
         let (yl, yr) = unzip(y, w);
-        T::add(
-            &T::add(
-                &T::add(&self.value[xr][yr], &self.value[xl][yl]),
+        T::op(
+            &T::op(
+                &T::op(&self.value[xr][yr], &self.value[xl][yl]),
                 &T::inv(&self.value[xr][yl]),
             ),
             &T::inv(&self.value[xl][yr]),
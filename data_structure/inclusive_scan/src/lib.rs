@@ -1,25 +1,20 @@
 pub mod inclusive_scan_2d;
 
-use std::ops::{Bound, RangeBounds};
+use algebra::monoid::Group;
 
-pub trait InclusiveScanOp {
-    type Value: Clone;
-    fn e() -> Self::Value;
-    fn mul(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
-    fn inv(val: &Self::Value) -> Self::Value;
-}
+use std::ops::{Bound, RangeBounds};
 
-pub struct InclusiveScan<T: InclusiveScanOp> {
+pub struct InclusiveScan<T: Group> {
     scan: Vec<T::Value>,
 }
 
-impl<T: InclusiveScanOp> InclusiveScan<T> {
+impl<T: Group> InclusiveScan<T> {
     pub fn new(a: &[T::Value]) -> Self {
         Self {
             scan: std::iter::once(T::e())
                 .chain(a.to_vec())
                 .scan(T::e(), |acc, a| {
-                    *acc = T::mul(acc, &a);
+                    *acc = T::op(acc, &a);
                     Some(acc.clone())
                 })
                 .collect::<Vec<_>>(),
@@ -28,7 +23,7 @@ impl<T: InclusiveScanOp> InclusiveScan<T> {
 
     pub fn fold<R: RangeBounds<usize>>(&self, range: R) -> T::Value {
         let (l, r) = unzip(range, self.scan.len() - 1);
-        T::mul(&T::inv(&self.scan[l]), &self.scan[r])
+        T::op(&T::inv(&self.scan[l]), &self.scan[r])
     }
 }
 
@@ -1,29 +1,24 @@
-pub trait DualSegmentTreeOp {
-    type Value: Copy;
-    type Map;
-    fn is_commutative() -> bool;
-    fn id() -> Self::Map;
-    fn comp(lhs: &Self::Map, rhs: &Self::Map) -> Self::Map;
-    fn act(val: &mut Self::Value, map: &Self::Map);
-}
+use algebra::monoid::{Action, CommutativeMonoid, Monoid, Semigroup};
+
+type Map<T> = <<T as Action>::Map as Semigroup>::Value;
 
 use std::ops::RangeBounds;
-pub struct DualSegmentTree<T: DualSegmentTreeOp> {
+pub struct DualSegmentTree<T: Action> {
     values: Vec<T::Value>,
-    maps: Vec<T::Map>,
+    maps: Vec<Map<T>>,
 }
 
-impl<T: DualSegmentTreeOp> DualSegmentTree<T> {
+impl<T: Action> DualSegmentTree<T> {
     pub fn new(values: &[T::Value]) -> Self {
         let n = values.len().next_power_of_two();
         Self {
             values: values.to_vec(),
-            maps: (0..2 * n).map(|_| T::id()).collect(),
+            maps: vec![T::Map::e(); 2 * n],
         }
     }
 
     pub fn get_at(&self, idx: usize) -> T::Value {
-        let mut value = self.values[idx];
+        let mut value = self.values[idx].clone();
         let mut idx = idx + self.maps.len() / 2;
         while idx > 0 {
             T::act(&mut value, &self.maps[idx]);
@@ -32,12 +27,16 @@ impl<T: DualSegmentTreeOp> DualSegmentTree<T> {
         value
     }
 
-    pub fn act(&mut self, range: impl RangeBounds<usize>, x: T::Map) {
+    pub fn act(&mut self, range: impl RangeBounds<usize>, x: Map<T>) {
+        self.apply(range, x, true);
+    }
+
+    fn apply(&mut self, range: impl RangeBounds<usize>, x: Map<T>, propagate: bool) {
         let n = self.maps.len() / 2;
         let (l, r) = unzip(range, n);
         let (mut l, mut r) = (l + n, r + n);
 
-        if !T::is_commutative() {
+        if propagate {
             for i in (1..=n.trailing_zeros()).rev() {
                 if (l >> i) << i != l {
                     self.sink(l >> i);
@@ -50,12 +49,12 @@ impl<T: DualSegmentTreeOp> DualSegmentTree<T> {
 
         while l < r {
             if l & 1 == 1 {
-                self.maps[l] = T::comp(&self.maps[l], &x);
+                self.maps[l] = T::Map::op(&self.maps[l], &x);
                 l += 1;
             }
             if r & 1 == 1 {
                 r -= 1;
-                self.maps[r] = T::comp(&self.maps[r], &x);
+                self.maps[r] = T::Map::op(&self.maps[r], &x);
             }
             l >>= 1;
             r >>= 1;
@@ -63,11 +62,38 @@ impl<T: DualSegmentTreeOp> DualSegmentTree<T> {
     }
 
     fn sink(&mut self, i: usize) {
-        let a = std::mem::replace(&mut self.maps[i], T::id());
-        self.maps[2 * i] = T::comp(&self.maps[2 * i], &a);
-        self.maps[2 * i + 1] = T::comp(&self.maps[2 * i + 1], &a);
+        let a = std::mem::replace(&mut self.maps[i], T::Map::e());
+        self.maps[2 * i] = T::Map::op(&self.maps[2 * i], &a);
+        self.maps[2 * i + 1] = T::Map::op(&self.maps[2 * i + 1], &a);
+    }
+}
+
+pub struct CommutativeDualSegmentTree<T: Action>
+where
+    T::Map: CommutativeMonoid,
+{
+    tree: DualSegmentTree<T>,
+}
+
+impl<T: Action> CommutativeDualSegmentTree<T>
+where
+    T::Map: CommutativeMonoid,
+{
+    pub fn new(values: &[T::Value]) -> Self {
+        Self {
+            tree: DualSegmentTree::new(values),
+        }
+    }
+
+    pub fn get_at(&self, idx: usize) -> T::Value {
+        self.tree.get_at(idx)
+    }
+
+    pub fn act(&mut self, range: impl RangeBounds<usize>, x: Map<T>) {
+        self.tree.apply(range, x, false);
     }
 }
+
 fn unzip<R: RangeBounds<usize>>(range: R, n: usize) -> (usize, usize) {
     use std::ops::Bound;
     let start = match range.start_bound() {
@@ -1,21 +1,15 @@
-pub trait LazySegmentTreeOp {
-    type Value: Copy;
-    type Map: Copy;
-    fn e() -> Self::Value;
-    fn op(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
-    fn id() -> Self::Map;
-    fn comp(lhs: &Self::Map, rhs: &Self::Map) -> Self::Map;
-    fn act(val: &mut Self::Value, map: &Self::Map);
-}
+use algebra::monoid::{Action, Monoid, Semigroup};
+
+type Map<T> = <<T as Action>::Map as Semigroup>::Value;
 
 use std::ops::RangeBounds;
-pub struct LazySegmentTree<T: LazySegmentTreeOp> {
+pub struct LazySegmentTree<T: Action> {
     values: Vec<T::Value>,
-    maps: Vec<T::Map>,
+    maps: Vec<Map<T>>,
     len: usize,
 }
 
-impl<T: LazySegmentTreeOp> LazySegmentTree<T> {
+impl<T: Action> LazySegmentTree<T> {
     pub fn new(a: &[T::Value]) -> Self {
         let n = a.len().next_power_of_two();
         let mut values = vec![T::e(); 2 * n];
@@ -25,7 +19,7 @@ impl<T: LazySegmentTreeOp> LazySegmentTree<T> {
         }
         Self {
             values,
-            maps: vec![T::id(); 2 * n],
+            maps: vec![T::Map::e(); 2 * n],
             len: a.len(),
         }
     }
@@ -36,7 +30,7 @@ impl<T: LazySegmentTreeOp> LazySegmentTree<T> {
         for k in (1..=n.trailing_zeros()).rev() {
             self.sink_map(i >> k);
         }
-        self.values[i]
+        self.values[i].clone()
     }
 
     pub fn fold(&mut self, range: impl RangeBounds<usize>) -> T::Value {
@@ -81,7 +75,7 @@ impl<T: LazySegmentTreeOp> LazySegmentTree<T> {
         }
     }
 
-    pub fn act(&mut self, range: impl RangeBounds<usize>, x: T::Map) {
+    pub fn act(&mut self, range: impl RangeBounds<usize>, x: Map<T>) {
         let n = self.values.len() / 2;
         let (l, r) = unzip(range, n);
         let (l, r) = (l + n, r + n);
@@ -155,9 +149,9 @@ impl<T: LazySegmentTreeOp> LazySegmentTree<T> {
         self.len
     }
 
-    fn apply(&mut self, i: usize, x: &T::Map) {
+    fn apply(&mut self, i: usize, x: &Map<T>) {
         T::act(&mut self.values[i], x);
-        self.maps[i] = T::comp(&self.maps[i], x);
+        self.maps[i] = T::Map::op(&self.maps[i], x);
     }
 
     fn float_value(&mut self, i: usize) {
@@ -165,7 +159,7 @@ impl<T: LazySegmentTreeOp> LazySegmentTree<T> {
     }
 
     fn sink_map(&mut self, i: usize) {
-        let a = std::mem::replace(&mut self.maps[i], T::id());
+        let a = std::mem::replace(&mut self.maps[i], T::Map::e());
         self.apply(2 * i, &a);
         self.apply(2 * i + 1, &a);
     }
@@ -1,18 +1,16 @@
 pub mod dual_segment_tree;
 pub mod lazy_segment_tree;
+#[cfg(test)]
+mod tests;
 
-pub trait SegmentTreeOp {
-    type Value: Copy;
-    fn e() -> Self::Value;
-    fn op(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value;
-}
+use algebra::monoid::Monoid;
 
 use std::ops::RangeBounds;
-pub struct SegmentTree<T: SegmentTreeOp> {
+pub struct SegmentTree<T: Monoid> {
     values: Vec<T::Value>,
     len: usize,
 }
-impl<T: SegmentTreeOp> SegmentTree<T> {
+impl<T: Monoid> SegmentTree<T> {
     pub fn new(a: &[T::Value]) -> Self {
         let n = a.len().next_power_of_two();
         let mut values = vec![T::e(); 2 * n];
@@ -27,7 +25,7 @@ impl<T: SegmentTreeOp> SegmentTree<T> {
     }
 
     pub fn get_at(&self, idx: usize) -> T::Value {
-        self.values[idx + self.values.len() / 2]
+        self.values[idx + self.values.len() / 2].clone()
     }
 
     pub fn update_at(&mut self, i: usize, x: T::Value) {
@@ -0,0 +1,203 @@
+use super::{
+    dual_segment_tree::{CommutativeDualSegmentTree, DualSegmentTree},
+    lazy_segment_tree::LazySegmentTree,
+    SegmentTree,
+};
+use algebra::operator::{
+    Additive, Affine, Gcd, Max, Min, Multiplicative, RangeAddRangeMax, RangeAddRangeMin,
+    RangeAddRangeSum, RangeAffineRangeSum, RangeAssignRangeMax, RangeAssignRangeMin,
+    RangeAssignRangeSum, Xor,
+};
+use galois_field::GF;
+use xorshift::XorShift;
+
+type Fp = GF<998_244_353>;
+
+fn range(rng: &mut XorShift, n: usize) -> (usize, usize) {
+    let l = rng.below(n as u64 + 1) as usize;
+    let r = rng.below(n as u64 + 1) as usize;
+    (l.min(r), l.max(r))
+}
+
+fn small(rng: &mut XorShift) -> i64 {
+    rng.below(201) as i64 - 100
+}
+
+fn fp(rng: &mut XorShift) -> Fp {
+    Fp::new(rng.next_u64() as u32)
+}
+
+const N: usize = 37;
+const Q: usize = 1000;
+
+#[test]
+fn monoid_folds() {
+    let mut rng = XorShift::default();
+    let a = (0..N).map(|_| small(&mut rng)).collect::<Vec<_>>();
+    let u = (0..N).map(|_| rng.next_u64() >> 1).collect::<Vec<_>>();
+    let f = (0..N).map(|_| fp(&mut rng)).collect::<Vec<_>>();
+    let add = SegmentTree::<Additive<i64>>::new(&a);
+    let min = SegmentTree::<Min<i64>>::new(&a);
+    let max = SegmentTree::<Max<i64>>::new(&a);
+    let gcd = SegmentTree::<Gcd<i64>>::new(&a);
+    let xor = SegmentTree::<Xor<u64>>::new(&u);
+    let mul = SegmentTree::<Multiplicative<Fp>>::new(&f);
+    for _ in 0..Q {
+        let (l, r) = range(&mut rng, N);
+        let a = &a[l..r];
+        assert_eq!(add.fold(l..r), a.iter().sum::<i64>());
+        assert_eq!(min.fold(l..r), a.iter().copied().min().unwrap_or(i64::MAX));
+        assert_eq!(max.fold(l..r), a.iter().copied().max().unwrap_or(i64::MIN));
+        let g = a.iter().fold(0, |g, &x| {
+            let (mut g, mut x) = (g, x.abs());
+            while x != 0 {
+                (g, x) = (x, g % x);
+            }
+            g
+        });
+        assert_eq!(gcd.fold(l..r), g);
+        assert_eq!(xor.fold(l..r), u[l..r].iter().fold(0, |acc, &x| acc ^ x));
+        assert_eq!(mul.fold(l..r), f[l..r].iter().product::<Fp>());
+    }
+}
+
+#[test]
+fn affine_composition_order() {
+    let mut rng = XorShift::default();
+    let maps = (0..N)
+        .map(|_| (fp(&mut rng), fp(&mut rng)))
+        .collect::<Vec<_>>();
+    let tree = SegmentTree::<Affine<Fp>>::new(&maps);
+    for _ in 0..Q {
+        let (l, r) = range(&mut rng, N);
+        let x = fp(&mut rng);
+        let (a, b) = tree.fold(l..r);
+        let expected = maps[l..r].iter().fold(x, |x, &(a, b)| a * x + b);
+        assert_eq!(a * x + b, expected);
+    }
+}
+
+#[test]
+fn lazy_range_add() {
+    let mut rng = XorShift::default();
+    let mut a = (0..N).map(|_| small(&mut rng)).collect::<Vec<_>>();
+    a[0] = i64::MAX;
+    a[N - 1] = i64::MIN;
+    let mut sum = LazySegmentTree::<RangeAddRangeSum<i128>>::new(
+        &a.iter().map(|&x| (x as i128, 1)).collect::<Vec<_>>(),
+    );
+    let mut min = LazySegmentTree::<RangeAddRangeMin<i64>>::new(
+        &a.iter().map(|&x| Some(x)).collect::<Vec<_>>(),
+    );
+    let mut max = LazySegmentTree::<RangeAddRangeMax<i64>>::new(
+        &a.iter().map(|&x| Some(x)).collect::<Vec<_>>(),
+    );
+    for _ in 0..Q {
+        let (l, r) = range(&mut rng, N);
+        if rng.next_u64().is_multiple_of(2) {
+            let x = small(&mut rng);
+            let (l, r) = (l.max(1), r.min(N - 1).max(l.max(1)));
+            sum.act(l..r, x as i128);
+            min.act(l..r, x);
+            max.act(l..r, x);
+            for a in a[l..r].iter_mut() {
+                *a += x;
+            }
+        } else {
+            let a = &a[l..r];
+            assert_eq!(sum.fold(l..r).0, a.iter().map(|&x| x as i128).sum::<i128>());
+            assert_eq!(min.fold(l..r), a.iter().copied().min());
+            assert_eq!(max.fold(l..r), a.iter().copied().max());
+        }
+    }
+    min.act(..1, -1);
+    max.act(N - 1.., 1);
+    assert_eq!(min.get_at(0), Some(i64::MAX - 1));
+    assert_eq!(max.get_at(N - 1), Some(i64::MIN + 1));
+}
+
+#[test]
+fn lazy_range_assign() {
+    let mut rng = XorShift::default();
+    let mut a = (0..N).map(|_| small(&mut rng)).collect::<Vec<_>>();
+    let mut sum = LazySegmentTree::<RangeAssignRangeSum<i64>>::new(
+        &a.iter().map(|&x| (x, 1)).collect::<Vec<_>>(),
+    );
+    let mut min = LazySegmentTree::<RangeAssignRangeMin<i64>>::new(&a);
+    let mut max = LazySegmentTree::<RangeAssignRangeMax<i64>>::new(&a);
+    for _ in 0..Q {
+        let (l, r) = range(&mut rng, N);
+        if rng.next_u64().is_multiple_of(2) {
+            let x = small(&mut rng);
+            sum.act(l..r, Some(x));
+            min.act(l..r, Some(x));
+            max.act(l..r, Some(x));
+            a[l..r].fill(x);
+        } else {
+            let a = &a[l..r];
+            assert_eq!(sum.fold(l..r).0, a.iter().sum::<i64>());
+            assert_eq!(min.fold(l..r), a.iter().copied().min().unwrap_or(i64::MAX));
+            assert_eq!(max.fold(l..r), a.iter().copied().max().unwrap_or(i64::MIN));
+        }
+    }
+}
+
+#[test]
+fn lazy_range_affine() {
+    let mut rng = XorShift::default();
+    let mut a = (0..N).map(|_| fp(&mut rng)).collect::<Vec<_>>();
+    let mut tree = LazySegmentTree::<RangeAffineRangeSum<Fp>>::new(
+        &a.iter().map(|&x| (x, Fp::new(1))).collect::<Vec<_>>(),
+    );
+    for _ in 0..Q {
+        let (l, r) = range(&mut rng, N);
+        if rng.next_u64().is_multiple_of(2) {
+            let (b, c) = (fp(&mut rng), fp(&mut rng));
+            tree.act(l..r, (b, c));
+            for a in a[l..r].iter_mut() {
+                *a = b * *a + c;
+            }
+        } else {
+            assert_eq!(tree.fold(l..r).0, a[l..r].iter().sum::<Fp>());
+        }
+    }
+}
+
+#[test]
+fn dual_composition_order() {
+    let mut rng = XorShift::default();
+    let mut a = (0..N).map(|_| fp(&mut rng)).collect::<Vec<_>>();
+    let mut b = (0..N).map(|_| small(&mut rng)).collect::<Vec<_>>();
+    let mut c = (0..N).map(|_| small(&mut rng)).collect::<Vec<_>>();
+    let mut affine = DualSegmentTree::<RangeAffineRangeSum<Fp>>::new(
+        &a.iter().map(|&x| (x, Fp::new(1))).collect::<Vec<_>>(),
+    );
+    let mut assign = DualSegmentTree::<RangeAssignRangeSum<i64>>::new(
+        &b.iter().map(|&x| (x, 1)).collect::<Vec<_>>(),
+    );
+    let mut add = CommutativeDualSegmentTree::<RangeAddRangeSum<i64>>::new(
+        &c.iter().map(|&x| (x, 1)).collect::<Vec<_>>(),
+    );
+    for _ in 0..Q {
+        let (l, r) = range(&mut rng, N);
+        let (p, q) = (fp(&mut rng), fp(&mut rng));
+        affine.act(l..r, (p, q));
+        for a in a[l..r].iter_mut() {
+            *a = p * *a + q;
+        }
+        let (l, r) = range(&mut rng, N);
+        let x = small(&mut rng);
+        assign.act(l..r, Some(x));
+        b[l..r].fill(x);
+        let (l, r) = range(&mut rng, N);
+        let x = small(&mut rng);
+        add.act(l..r, x);
+        for c in c[l..r].iter_mut() {
+            *c += x;
+        }
+        let i = (rng.next_u64() % N as u64) as usize;
+        assert_eq!(affine.get_at(i).0, a[i]);
+        assert_eq!(assign.get_at(i).0, b[i]);
+        assert_eq!(add.get_at(i).0, c[i]);
+    }
+}
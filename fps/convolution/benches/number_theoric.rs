@@ -0,0 +1,36 @@
+use convolution::{
+    number_theoric::{MontgomeryNumberTheoric998244353, NumberTheoric998244353},
+    Convolution,
+};
+use galois_field::{montgomery::MontgomeryGF, GF};
+
+use std::time::{Duration, Instant};
+
+const LEN: usize = 1 << 19;
+const ROUNDS: usize = 5;
+
+fn time<T: Convolution>(a: &[T::Value], b: &[T::Value]) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        std::hint::black_box(T::convolution(a, b));
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let a = (0..LEN as u32)
+        .map(|i| i.wrapping_mul(0x9e37_79b9))
+        .collect::<Vec<_>>();
+    let b = (0..LEN as u32)
+        .map(|i| i.wrapping_mul(0x85eb_ca6b))
+        .collect::<Vec<_>>();
+    let plain = time::<NumberTheoric998244353>(
+        &a.iter().map(|&x| GF::new(x)).collect::<Vec<_>>(),
+        &b.iter().map(|&x| GF::new(x)).collect::<Vec<_>>(),
+    );
+    let montgomery = time::<MontgomeryNumberTheoric998244353>(
+        &a.iter().map(|&x| MontgomeryGF::new(x)).collect::<Vec<_>>(),
+        &b.iter().map(|&x| MontgomeryGF::new(x)).collect::<Vec<_>>(),
+    );
+    println!("GF: {:?}, MontgomeryGF: {:?}", plain, montgomery);
+}
@@ -0,0 +1,110 @@
+use super::Convolution;
+use algebra::Ring;
+use sieve::divisor_transform::{divisor_mobius, divisor_zeta, multiple_mobius, multiple_zeta};
+
+use std::marker::PhantomData;
+
+fn pointwise<C: Convolution>(lhs: &[C::Value], rhs: &[C::Value]) -> Vec<C::Value> {
+    let n = lhs.len().max(rhs.len());
+    let mut f = vec![C::e(); n];
+    let mut g = vec![C::e(); n];
+    f[..lhs.len()].copy_from_slice(lhs);
+    g[..rhs.len()].copy_from_slice(rhs);
+
+    C::fourier_transform(&mut f);
+    C::fourier_transform(&mut g);
+
+    let mut h = f
+        .iter()
+        .zip(g.iter())
+        .map(|(f, g)| C::mul(f, g))
+        .collect::<Vec<_>>();
+
+    C::inverse_transform(&mut h);
+    h
+}
+
+pub struct GcdConvolution<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Ring + Copy> Convolution for GcdConvolution<T> {
+    type Value = T;
+    fn e() -> Self::Value {
+        T::zero()
+    }
+    fn mul(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value {
+        *lhs * *rhs
+    }
+    fn convolution(lhs: &[Self::Value], rhs: &[Self::Value]) -> Vec<Self::Value> {
+        pointwise::<Self>(lhs, rhs)
+    }
+    fn fourier_transform(a: &mut [Self::Value]) {
+        multiple_zeta(a);
+    }
+    fn inverse_transform(a: &mut [Self::Value]) {
+        multiple_mobius(a);
+    }
+}
+
+pub struct LcmConvolution<T> {
+    phantom: PhantomData<T>,
+}
+impl<T: Ring + Copy> Convolution for LcmConvolution<T> {
+    type Value = T;
+    fn e() -> Self::Value {
+        T::zero()
+    }
+    fn mul(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value {
+        *lhs * *rhs
+    }
+    fn convolution(lhs: &[Self::Value], rhs: &[Self::Value]) -> Vec<Self::Value> {
+        pointwise::<Self>(lhs, rhs)
+    }
+    fn fourier_transform(a: &mut [Self::Value]) {
+        divisor_zeta(a);
+    }
+    fn inverse_transform(a: &mut [Self::Value]) {
+        divisor_mobius(a);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GcdConvolution, LcmConvolution};
+    use crate::Convolution;
+
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for (n, m) in [(1, 1), (2, 5), (13, 13), (30, 17), (100, 64)] {
+            let f = (0..n as i64)
+                .map(|i| (i * 31 + 7) % 19 - 9)
+                .collect::<Vec<_>>();
+            let g = (0..m as i64)
+                .map(|i| (i * 17 + 3) % 13 - 6)
+                .collect::<Vec<_>>();
+            let len = n.max(m);
+            let (mut by_gcd, mut by_lcm) = (vec![0; len], vec![0; len]);
+            by_gcd[0] = f[0] * g[0];
+            by_lcm[0] = f[0] * g[0];
+            for i in 1..n {
+                for j in 1..m {
+                    let d = gcd(i, j);
+                    by_gcd[d] += f[i] * g[j];
+                    if i / d * j < len {
+                        by_lcm[i / d * j] += f[i] * g[j];
+                    }
+                }
+            }
+            assert_eq!(GcdConvolution::<i64>::convolution(&f, &g), by_gcd);
+            assert_eq!(LcmConvolution::<i64>::convolution(&f, &g), by_lcm);
+        }
+    }
+}
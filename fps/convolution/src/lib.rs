@@ -1,4 +1,5 @@
 pub mod bitwise;
+pub mod divisor;
 pub mod number_theoric;
 
 pub trait Convolution {
@@ -1,5 +1,5 @@
 use super::Convolution;
-use galois_field::GF;
+use galois_field::{montgomery::MontgomeryGF, GF};
 
 const SUM_E: [u32; 22] = [
     911660635, 509520358, 369330050, 332049552, 983190778, 123842337, 238493703, 975955924,
@@ -12,47 +12,98 @@ const SUM_IE: [u32; 22] = [
     121186627, 608385704, 438932459, 359477183, 824071951, 103369235,
 ];
 
-pub enum NumberTheoric998244353 {}
-impl Convolution for NumberTheoric998244353 {
-    type Value = GF<998_244_353>;
-    fn e() -> Self::Value {
-        GF::new(0)
-    }
-    fn mul(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value {
-        lhs * rhs
-    }
-    fn fourier_transform(a: &mut [Self::Value]) {
-        let n = a.len();
-        let b = n.trailing_zeros() as usize;
-        for k in (0..b).rev() {
-            let k = 1 << k;
-            let mut coef = GF::new(1);
-            for (i, a) in a.chunks_exact_mut(2 * k).enumerate() {
-                let (x, y) = a.split_at_mut(k);
-                for (x, y) in x.iter_mut().zip(y.iter_mut()) {
-                    (*x, *y) = (*x + *y * coef, *x - *y * coef);
+macro_rules! number_theoric_998244353 {
+    ($name:ident, $t:ty) => {
+        pub enum $name {}
+        impl Convolution for $name {
+            type Value = $t;
+            fn e() -> Self::Value {
+                <$t>::new(0)
+            }
+            fn mul(lhs: &Self::Value, rhs: &Self::Value) -> Self::Value {
+                lhs * rhs
+            }
+            fn fourier_transform(a: &mut [Self::Value]) {
+                let n = a.len();
+                let b = n.trailing_zeros() as usize;
+                let sum_e = SUM_E.map(<$t>::new);
+                for k in (0..b).rev() {
+                    let k = 1 << k;
+                    let mut coef = <$t>::new(1);
+                    for (i, a) in a.chunks_exact_mut(2 * k).enumerate() {
+                        let (x, y) = a.split_at_mut(k);
+                        for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+                            (*x, *y) = (*x + *y * coef, *x - *y * coef);
+                        }
+                        coef *= sum_e[(!i).trailing_zeros() as usize];
+                    }
                 }
-                coef *= GF::new(SUM_E[(!i).trailing_zeros() as usize]);
             }
-        }
-    }
-    fn inverse_transform(a: &mut [Self::Value]) {
-        let n = a.len();
-        let b = n.trailing_zeros() as usize;
-        for k in 0..b {
-            let k = 1 << k;
-            let mut coef = GF::new(1);
-            for (i, a) in a.chunks_exact_mut(2 * k).enumerate() {
-                let (x, y) = a.split_at_mut(k);
-                for (x, y) in x.iter_mut().zip(y.iter_mut()) {
-                    (*x, *y) = (*x + *y, (*x - *y) * coef);
+            fn inverse_transform(a: &mut [Self::Value]) {
+                let n = a.len();
+                let b = n.trailing_zeros() as usize;
+                let sum_ie = SUM_IE.map(<$t>::new);
+                for k in 0..b {
+                    let k = 1 << k;
+                    let mut coef = <$t>::new(1);
+                    for (i, a) in a.chunks_exact_mut(2 * k).enumerate() {
+                        let (x, y) = a.split_at_mut(k);
+                        for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+                            (*x, *y) = (*x + *y, (*x - *y) * coef);
+                        }
+                        coef *= sum_ie[(!i).trailing_zeros() as usize];
+                    }
+                }
+                let coef = <$t>::new(2).inv().pow(b as u32);
+                for a in a.iter_mut() {
+                    *a *= coef;
                 }
-                coef *= GF::new(SUM_IE[(!i).trailing_zeros() as usize]);
             }
         }
-        let coef = GF::new(2).inv().pow(b as u32);
-        for a in a.iter_mut() {
-            *a *= coef;
+    };
+}
+number_theoric_998244353!(NumberTheoric998244353, GF<998_244_353>);
+number_theoric_998244353!(MontgomeryNumberTheoric998244353, MontgomeryGF<998_244_353>);
+
+#[cfg(test)]
+mod tests {
+    use super::{MontgomeryNumberTheoric998244353, NumberTheoric998244353};
+    use crate::Convolution;
+    use galois_field::{montgomery::MontgomeryGF, GF};
+    use xorshift::XorShift;
+
+    #[test]
+    fn montgomery_matches_plain() {
+        let mut rng = XorShift::default();
+        for (n, m) in [(1, 1), (1, 7), (5, 3), (64, 64), (100, 37), (1000, 1000)] {
+            let a = (0..n)
+                .map(|_| rng.below(998_244_353) as u32)
+                .collect::<Vec<_>>();
+            let b = (0..m)
+                .map(|_| rng.below(998_244_353) as u32)
+                .collect::<Vec<_>>();
+            let mut naive = vec![GF::<998_244_353>::new(0); n + m - 1];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    naive[i + j] += GF::new(x) * GF::new(y);
+                }
+            }
+            let plain = NumberTheoric998244353::convolution(
+                &a.iter().map(|&x| GF::new(x)).collect::<Vec<_>>(),
+                &b.iter().map(|&x| GF::new(x)).collect::<Vec<_>>(),
+            );
+            let montgomery = MontgomeryNumberTheoric998244353::convolution(
+                &a.iter().map(|&x| MontgomeryGF::new(x)).collect::<Vec<_>>(),
+                &b.iter().map(|&x| MontgomeryGF::new(x)).collect::<Vec<_>>(),
+            );
+            assert_eq!(plain, naive);
+            assert_eq!(
+                montgomery.iter().map(|x| x.value()).collect::<Vec<_>>(),
+                naive
+                    .iter()
+                    .map(|x| x.to_string().parse().unwrap())
+                    .collect::<Vec<u32>>()
+            );
         }
     }
 }
@@ -0,0 +1,95 @@
+pub mod linear_recurrence;
+/// Combinatorial sequences by power series arithmetic, fixed to `GF<998244353>`
+/// because `NumberTheoric998244353` is the only NTT in `convolution`. For other
+/// moduli use the `O(n^2)` tables in `galois_field::combinatorics`.
+pub mod sequence;
+pub mod sparse_matrix;
+
+use convolution::{number_theoric::NumberTheoric998244353, Convolution};
+use galois_field::{combinatorics::Combinatorics, GF};
+
+type Fp = GF<998_244_353>;
+
+fn multiply(lhs: &[Fp], rhs: &[Fp], n: usize) -> Vec<Fp> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![Fp::new(0); n];
+    }
+    let mut res = NumberTheoric998244353::convolution(lhs, rhs);
+    res.resize(n, Fp::new(0));
+    res
+}
+
+fn inv(f: &[Fp], n: usize) -> Vec<Fp> {
+    assert!(f[0] != Fp::new(0));
+    let mut g = vec![f[0].inv()];
+    let mut m = 1;
+    while m < n {
+        m *= 2;
+        let mut h = multiply(&f[..m.min(f.len())], &g, m);
+        for h in h.iter_mut() {
+            *h = -*h;
+        }
+        h[0] += Fp::new(2);
+        g = multiply(&g, &h, m);
+    }
+    g.truncate(n);
+    g
+}
+
+fn log(f: &[Fp], n: usize) -> Vec<Fp> {
+    assert!(f[0] == Fp::new(1));
+    if n == 0 {
+        return vec![];
+    }
+    let comb = Combinatorics::new(n);
+    let df = f
+        .iter()
+        .enumerate()
+        .skip(1)
+        .take(n)
+        .map(|(i, &a)| a * Fp::new(i as u32))
+        .collect::<Vec<_>>();
+    let h = multiply(&df, &inv(f, n), n - 1);
+    let mut res = vec![Fp::new(0)];
+    res.extend(h.iter().enumerate().map(|(i, &a)| a * comb.inv(i + 1)));
+    res
+}
+
+fn exp(f: &[Fp], n: usize) -> Vec<Fp> {
+    assert!(f.is_empty() || f[0] == Fp::new(0));
+    let mut g = vec![Fp::new(1)];
+    let mut m = 1;
+    while m < n {
+        m *= 2;
+        let mut h = log(&g, m);
+        for (h, &a) in h.iter_mut().zip(f) {
+            *h = a - *h;
+        }
+        for h in h.iter_mut().skip(f.len()) {
+            *h = -*h;
+        }
+        h[0] += Fp::new(1);
+        g = multiply(&g, &h, m);
+    }
+    g.truncate(n);
+    g
+}
+
+fn taylor_shift(f: &[Fp], c: Fp) -> Vec<Fp> {
+    let n = f.len();
+    let comb = Combinatorics::new(n);
+    let a = f
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, &a)| a * comb.fact(i))
+        .collect::<Vec<_>>();
+    let mut pow = Fp::new(1);
+    let mut b = vec![];
+    for i in 0..n {
+        b.push(pow * comb.inv_fact(i));
+        pow *= c;
+    }
+    let h = multiply(&a, &b, n);
+    (0..n).map(|k| h[n - 1 - k] * comb.inv_fact(k)).collect()
+}
@@ -0,0 +1,116 @@
+use galois_field::GF;
+
+fn multiply_naive<const MOD: u32>(lhs: &[GF<MOD>], rhs: &[GF<MOD>]) -> Vec<GF<MOD>> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![GF::new(0); lhs.len() + rhs.len() - 1];
+    for (i, &a) in lhs.iter().enumerate() {
+        for (r, &b) in res[i..].iter_mut().zip(rhs) {
+            *r += a * b;
+        }
+    }
+    res
+}
+
+pub fn berlekamp_massey<const MOD: u32>(a: &[GF<MOD>]) -> Vec<GF<MOD>> {
+    let mut c = vec![GF::new(1)];
+    let mut b = vec![GF::new(1)];
+    let mut l = 0;
+    let mut m = 1;
+    let mut prev = GF::new(1);
+    for n in 0..a.len() {
+        let d = c
+            .iter()
+            .zip(a[..=n].iter().rev())
+            .map(|(&c, &a)| c * a)
+            .sum::<GF<MOD>>();
+        if d == GF::new(0) {
+            m += 1;
+            continue;
+        }
+        let coef = d / prev;
+        let t = c.clone();
+        if c.len() < b.len() + m {
+            c.resize(b.len() + m, GF::new(0));
+        }
+        for (c, &b) in c[m..].iter_mut().zip(&b) {
+            *c -= coef * b;
+        }
+        if 2 * l <= n {
+            l = n + 1 - l;
+            b = t;
+            prev = d;
+            m = 1;
+        } else {
+            m += 1;
+        }
+    }
+    c.resize(l + 1, GF::new(0));
+    c[1..].iter().map(|&c| -c).collect()
+}
+
+pub fn bostan_mori<const MOD: u32>(p: &[GF<MOD>], q: &[GF<MOD>], mut n: u64) -> GF<MOD> {
+    assert!(q[0] != GF::new(0));
+    let mut p = p.to_vec();
+    let mut q = q.to_vec();
+    while n > 0 {
+        let q_neg = q
+            .iter()
+            .enumerate()
+            .map(|(i, &a)| if i % 2 == 1 { -a } else { a })
+            .collect::<Vec<_>>();
+        let u = multiply_naive(&p, &q_neg);
+        let v = multiply_naive(&q, &q_neg);
+        p = u.into_iter().skip((n % 2) as usize).step_by(2).collect();
+        q = v.into_iter().step_by(2).collect();
+        n /= 2;
+    }
+    p.first().copied().unwrap_or(GF::new(0)) / q[0]
+}
+
+pub fn nth_term<const MOD: u32>(a: &[GF<MOD>], n: u64) -> GF<MOD> {
+    if n < a.len() as u64 {
+        return a[n as usize];
+    }
+    let c = berlekamp_massey(a);
+    let d = c.len();
+    let mut q = vec![GF::new(1)];
+    q.extend(c.iter().map(|&c| -c));
+    let mut p = multiply_naive(&a[..d], &q);
+    p.truncate(d);
+    bostan_mori(&p, &q, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{berlekamp_massey, nth_term};
+    use galois_field::GF;
+
+    type Fp = GF<998_244_353>;
+
+    #[test]
+    fn recovers_recurrence() {
+        let c = [3, 0, 998_244_352, 5].map(Fp::new);
+        let mut a = [1, 4, 1, 5].map(Fp::new).to_vec();
+        while a.len() < 200 {
+            let i = a.len();
+            let v = (0..c.len()).map(|j| c[j] * a[i - 1 - j]).sum();
+            a.push(v);
+        }
+        assert_eq!(berlekamp_massey(&a[..8]), c);
+        for n in 0..200 {
+            assert_eq!(nth_term(&a[..8], n as u64), a[n]);
+        }
+        assert_eq!(berlekamp_massey(&[Fp::new(0); 5]), []);
+    }
+
+    #[test]
+    fn fibonacci() {
+        let fib = [0, 1, 1, 2].map(Fp::new);
+        assert_eq!(
+            nth_term(&fib, 90),
+            Fp::new((2_880_067_194_370_816_120u64 % 998_244_353) as u32)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use super::{exp, inv, multiply, taylor_shift, Fp};
+use galois_field::combinatorics::Combinatorics;
+
+fn falling_factorial(n: usize) -> Vec<Fp> {
+    if n == 0 {
+        return vec![Fp::new(1)];
+    }
+    let h = falling_factorial(n / 2);
+    let g = taylor_shift(&h, -Fp::new((n / 2) as u32));
+    let mut res = multiply(&h, &g, n / 2 * 2 + 1);
+    if n % 2 == 1 {
+        let c = Fp::new((n - 1) as u32);
+        res.push(Fp::new(0));
+        for i in (0..n).rev() {
+            let a = res[i];
+            res[i + 1] += a;
+            res[i] = -a * c;
+        }
+    }
+    res
+}
+
+pub fn stirling_first(n: usize) -> Vec<Fp> {
+    falling_factorial(n)
+}
+
+pub fn stirling_second(n: usize) -> Vec<Fp> {
+    let comb = Combinatorics::new(n);
+    let a = (0..=n)
+        .map(|i| {
+            if i % 2 == 0 {
+                comb.inv_fact(i)
+            } else {
+                -comb.inv_fact(i)
+            }
+        })
+        .collect::<Vec<_>>();
+    let b = (0..=n)
+        .map(|j| Fp::new(j as u32).pow(n) * comb.inv_fact(j))
+        .collect::<Vec<_>>();
+    multiply(&a, &b, n + 1)
+}
+
+pub fn bell(n: usize) -> Vec<Fp> {
+    let comb = Combinatorics::new(n);
+    let mut f = (0..=n).map(|i| comb.inv_fact(i)).collect::<Vec<_>>();
+    f[0] = Fp::new(0);
+    exp(&f, n + 1)
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| a * comb.fact(i))
+        .collect()
+}
+
+pub fn partition(n: usize) -> Vec<Fp> {
+    let mut f = vec![Fp::new(0); n + 1];
+    f[0] = Fp::new(1);
+    for k in 1.. {
+        let p = k * (3 * k - 1) / 2;
+        if p > n {
+            break;
+        }
+        let s = if k % 2 == 0 { Fp::new(1) } else { -Fp::new(1) };
+        f[p] += s;
+        if p + k <= n {
+            f[p + k] += s;
+        }
+    }
+    inv(&f, n + 1)
+}
+
+pub fn bernoulli(n: usize) -> Vec<Fp> {
+    let comb = Combinatorics::new(n + 1);
+    let f = (0..=n).map(|i| comb.inv_fact(i + 1)).collect::<Vec<_>>();
+    inv(&f, n + 1)
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| a * comb.fact(i))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galois_field::combinatorics::{
+        bell_table, bernoulli_table, partition_table, stirling_first_table, stirling_second_table,
+    };
+
+    const MOD: u32 = 998_244_353;
+
+    #[test]
+    fn stirling_matches_table() {
+        let first = stirling_first_table::<MOD>(200);
+        let second = stirling_second_table::<MOD>(200);
+        for n in 0..=200 {
+            assert_eq!(stirling_first(n), first[n]);
+            assert_eq!(stirling_second(n), second[n]);
+        }
+    }
+
+    #[test]
+    fn bell_partition_bernoulli_match_table() {
+        for n in [0, 1, 2, 3, 7, 64, 100, 513] {
+            assert_eq!(bell(n), bell_table::<MOD>(n));
+            assert_eq!(partition(n), partition_table::<MOD>(n));
+            assert_eq!(bernoulli(n), bernoulli_table::<MOD>(n));
+        }
+    }
+}
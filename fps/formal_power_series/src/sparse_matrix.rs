@@ -0,0 +1,282 @@
+use super::linear_recurrence::berlekamp_massey;
+use galois_field::GF;
+use matrix::Matrix;
+use xorshift::XorShift;
+
+const ATTEMPTS: usize = 32;
+
+fn random_gf<const MOD: u32>(rng: &mut XorShift) -> GF<MOD> {
+    GF::new(rng.below(MOD as u64) as u32)
+}
+
+fn random_nonzero_gf<const MOD: u32>(rng: &mut XorShift) -> GF<MOD> {
+    GF::new(rng.below(MOD as u64 - 1) as u32 + 1)
+}
+
+#[derive(Clone, Debug)]
+pub struct SparseMatrix<const MOD: u32> {
+    h: usize,
+    w: usize,
+    entries: Vec<(usize, usize, GF<MOD>)>,
+}
+
+impl<const MOD: u32> SparseMatrix<MOD> {
+    pub fn new(h: usize, w: usize, entries: Vec<(usize, usize, GF<MOD>)>) -> Self {
+        assert!(entries.iter().all(|&(i, j, _)| i < h && j < w));
+        Self { h, w, entries }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.h, self.w)
+    }
+
+    pub fn apply(&self, v: &[GF<MOD>]) -> Vec<GF<MOD>> {
+        assert_eq!(self.w, v.len());
+        let mut res = vec![GF::new(0); self.h];
+        for &(i, j, a) in &self.entries {
+            res[i] += a * v[j];
+        }
+        res
+    }
+
+    pub fn apply_transpose(&self, v: &[GF<MOD>]) -> Vec<GF<MOD>> {
+        assert_eq!(self.h, v.len());
+        let mut res = vec![GF::new(0); self.w];
+        for &(i, j, a) in &self.entries {
+            res[j] += a * v[i];
+        }
+        res
+    }
+
+    pub fn to_dense(&self) -> Matrix<GF<MOD>> {
+        let mut value = vec![GF::new(0); self.h * self.w];
+        for &(i, j, a) in &self.entries {
+            value[i * self.w + j] += a;
+        }
+        Matrix::new(self.h, self.w, &value)
+    }
+
+    pub fn determinant(&self) -> GF<MOD> {
+        assert_eq!(self.h, self.w);
+        if self.h == 0 {
+            return GF::new(1);
+        }
+        self.wiedemann_determinant()
+            .unwrap_or_else(|| self.to_dense().determinant())
+    }
+
+    fn wiedemann_determinant(&self) -> Option<GF<MOD>> {
+        let n = self.h;
+        let mut rng = XorShift::default();
+        for _ in 0..ATTEMPTS {
+            let d = (0..n)
+                .map(|_| random_nonzero_gf(&mut rng))
+                .collect::<Vec<GF<MOD>>>();
+            let c = minimal_polynomial(n, &mut rng, |v| {
+                let v = v.iter().zip(&d).map(|(&v, &d)| v * d).collect::<Vec<_>>();
+                self.apply(&v)
+            });
+            match c.last() {
+                Some(&c0) if c0 == GF::new(0) => return Some(GF::new(0)),
+                Some(&c0) if c.len() == n => {
+                    let det = if n % 2 == 1 { c0 } else { -c0 };
+                    return Some(det / d.iter().product::<GF<MOD>>());
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Returns the rank using Wiedemann's method on `D1 A^T D2 A D1` with random
+    /// diagonal preconditioners drawn from `seed`.
+    ///
+    /// This is Monte Carlo: the result never exceeds the true rank, and falls short
+    /// with probability roughly `4 w^2 / MOD`. Fields with `MOD < 4 w^2` use dense
+    /// elimination instead, which is exact. Retry with other seeds to boost confidence.
+    pub fn rank(&self, seed: u64) -> usize {
+        let w = self.w;
+        if self.h == 0 || w == 0 {
+            return 0;
+        }
+        if (MOD as u64) < 4 * (w as u64).pow(2) {
+            return self.to_dense().rank();
+        }
+        let mut rng = XorShift::new(seed);
+        let d1 = (0..w)
+            .map(|_| random_nonzero_gf(&mut rng))
+            .collect::<Vec<GF<MOD>>>();
+        let d2 = (0..self.h)
+            .map(|_| random_nonzero_gf(&mut rng))
+            .collect::<Vec<GF<MOD>>>();
+        let c = minimal_polynomial(w, &mut rng, |v| {
+            let v = v.iter().zip(&d1).map(|(&v, &d)| v * d).collect::<Vec<_>>();
+            let v = self.apply(&v);
+            let v = v.iter().zip(&d2).map(|(&v, &d)| v * d).collect::<Vec<_>>();
+            let v = self.apply_transpose(&v);
+            v.iter().zip(&d1).map(|(&v, &d)| v * d).collect()
+        });
+        match c.last() {
+            None => 0,
+            Some(&c0) if c0 == GF::new(0) => c.len() - 1,
+            Some(_) => c.len(),
+        }
+    }
+
+    pub fn solve(&self, b: &[GF<MOD>]) -> Option<(Vec<GF<MOD>>, Vec<Vec<GF<MOD>>>)> {
+        assert_eq!(self.h, b.len());
+        if self.w == 0 {
+            return b.iter().all(|&b| b == GF::new(0)).then(|| (vec![], vec![]));
+        }
+        if self.h == self.w
+            && self
+                .wiedemann_determinant()
+                .is_some_and(|d| d != GF::new(0))
+        {
+            if let Some(x) = self.wiedemann_solve(b) {
+                return Some((x, vec![]));
+            }
+        }
+        self.to_dense().solve(b)
+    }
+
+    fn wiedemann_solve(&self, b: &[GF<MOD>]) -> Option<Vec<GF<MOD>>> {
+        let n = self.h;
+        if b.iter().all(|&b| b == GF::new(0)) {
+            return Some(vec![GF::new(0); n]);
+        }
+        let mut rng = XorShift::default();
+        for _ in 0..ATTEMPTS {
+            let u = (0..n)
+                .map(|_| random_gf(&mut rng))
+                .collect::<Vec<GF<MOD>>>();
+            let mut a = Vec::with_capacity(2 * n);
+            let mut v = b.to_vec();
+            for _ in 0..2 * n {
+                a.push(u.iter().zip(&v).map(|(&u, &v)| u * v).sum());
+                v = self.apply(&v);
+            }
+            let c = berlekamp_massey(&a);
+            let Some(&c0) = c.last() else {
+                continue;
+            };
+            if c0 == GF::new(0) {
+                continue;
+            }
+            let mut x = b.to_vec();
+            for &c in &c[..c.len() - 1] {
+                x = self.apply(&x);
+                for (x, &b) in x.iter_mut().zip(b) {
+                    *x -= c * b;
+                }
+            }
+            let inv = c0.inv();
+            for x in x.iter_mut() {
+                *x *= inv;
+            }
+            if self.apply(&x) == b {
+                return Some(x);
+            }
+        }
+        None
+    }
+}
+
+fn minimal_polynomial<const MOD: u32>(
+    n: usize,
+    rng: &mut XorShift,
+    mut apply: impl FnMut(&[GF<MOD>]) -> Vec<GF<MOD>>,
+) -> Vec<GF<MOD>> {
+    let u = (0..n).map(|_| random_gf(rng)).collect::<Vec<GF<MOD>>>();
+    let mut v = (0..n).map(|_| random_gf(rng)).collect::<Vec<GF<MOD>>>();
+    let mut a = Vec::with_capacity(2 * n);
+    for _ in 0..2 * n {
+        a.push(u.iter().zip(&v).map(|(&u, &v)| u * v).sum());
+        v = apply(&v);
+    }
+    berlekamp_massey(&a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{random_gf, SparseMatrix};
+    use galois_field::GF;
+    use xorshift::XorShift;
+
+    fn random<const MOD: u32>(rng: &mut XorShift, h: usize, w: usize) -> SparseMatrix<MOD> {
+        let mut entries = vec![];
+        let kind = rng.next_u64() % 3;
+        for i in 0..h {
+            for j in 0..w {
+                let nonzero = match kind {
+                    0 => rng.next_u64().is_multiple_of(3),
+                    1 => j == i + 1,
+                    _ => j % 3 != 0 && rng.next_u64().is_multiple_of(2),
+                };
+                if nonzero {
+                    entries.push((i, j, random_gf(rng)));
+                }
+            }
+        }
+        SparseMatrix::new(h, w, entries)
+    }
+
+    fn check<const MOD: u32>() {
+        let mut rng = XorShift::default();
+        for _ in 0..300 {
+            let h = (rng.next_u64() % 10) as usize;
+            let w = if rng.next_u64().is_multiple_of(2) {
+                h
+            } else {
+                (rng.next_u64() % 10) as usize
+            };
+            let a = random::<MOD>(&mut rng, h, w);
+            let dense = a.to_dense();
+            if h > 0 && w > 0 {
+                let ranks = (0..16).map(|seed| a.rank(seed)).collect::<Vec<_>>();
+                assert!(ranks.iter().all(|&r| r <= dense.rank()));
+                assert_eq!(ranks.into_iter().max(), Some(dense.rank()));
+            }
+            if h == w && h > 0 {
+                assert_eq!(a.determinant(), dense.determinant());
+            }
+            let x = (0..w)
+                .map(|_| random_gf(&mut rng))
+                .collect::<Vec<GF<MOD>>>();
+            let b = if rng.next_u64().is_multiple_of(2) {
+                a.apply(&x)
+            } else {
+                (0..h).map(|_| random_gf(&mut rng)).collect()
+            };
+            let res = a.solve(&b);
+            if h > 0 && w > 0 {
+                assert_eq!(res.is_some(), dense.solve(&b).is_some());
+            }
+            if let Some((x, kernel)) = res {
+                assert_eq!(a.apply(&x), b);
+                assert_eq!(kernel.len(), w - dense.rank());
+                for v in &kernel {
+                    assert!(a.apply(v).iter().all(|&v| v == GF::new(0)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_dense() {
+        check::<998_244_353>();
+        check::<7>();
+        check::<2>();
+    }
+
+    #[test]
+    fn small_field_and_singular_systems() {
+        let e = SparseMatrix::<2>::new(2, 2, vec![(0, 0, GF::new(1)), (1, 1, GF::new(1))]);
+        assert_eq!(e.determinant(), GF::new(1));
+        let a = SparseMatrix::<998_244_353>::new(2, 2, vec![(0, 1, GF::new(1))]);
+        let (x, kernel) = a.solve(&[GF::new(1), GF::new(0)]).unwrap();
+        assert_eq!(a.apply(&x), [GF::new(1), GF::new(0)]);
+        assert_eq!(kernel.len(), 1);
+        assert_eq!(a.solve(&[GF::new(0), GF::new(1)]), None);
+    }
+}
@@ -0,0 +1,34 @@
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    pub fn new(seed: u64) -> Self {
+        let state = seed ^ 0x2545_f491_4f6c_dd1d;
+        Self {
+            state: if state == 0 {
+                0x2545_f491_4f6c_dd1d
+            } else {
+                state
+            },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0);
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+}
+
+impl Default for XorShift {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
@@ -1,13 +1,15 @@
+use algebra::monoid::{Monoid, Semigroup};
+
+type MonoidValue<T> = <<T as ValMonoid>::Monoid as Semigroup>::Value;
+
 pub trait ValMonoid {
-    type Monoid: Copy;
-    fn e() -> Self::Monoid;
-    fn op(lhs: &Self::Monoid, rhs: &Self::Monoid) -> Self::Monoid;
+    type Monoid: Monoid;
 
     type Value: Copy;
     type Edge: Copy;
     type Vertex: Copy;
-    fn monoidize(value: &Self::Value, edge: &Self::Edge) -> Self::Monoid;
-    fn valueize(monoid: &Self::Monoid, vertex: &Self::Vertex) -> Self::Value;
+    fn monoidize(value: &Self::Value, edge: &Self::Edge) -> MonoidValue<Self>;
+    fn valueize(monoid: &MonoidValue<Self>, vertex: &Self::Vertex) -> Self::Value;
 }
 
 pub fn rerooting<T: ValMonoid>(
@@ -16,7 +18,7 @@ pub fn rerooting<T: ValMonoid>(
     edge: &[(usize, usize, T::Edge, T::Edge)],
 ) -> Vec<T::Value> {
     if n == 1 {
-        return vec![T::valueize(&T::e(), &vertex[0])];
+        return vec![T::valueize(&T::Monoid::e(), &vertex[0])];
     }
     let mut child = vec![vec![]; n];
     for &(u, v, u2v, v2u) in edge {
@@ -38,33 +40,33 @@ pub fn rerooting<T: ValMonoid>(
         }
     }
 
-    let mut lower = vec![T::e(); n];
-    let mut upper = vec![T::e(); n];
-    let sample_value = T::valueize(&T::e(), &vertex[0]);
+    let mut lower = vec![T::Monoid::e(); n];
+    let mut upper = vec![T::Monoid::e(); n];
+    let sample_value = T::valueize(&T::Monoid::e(), &vertex[0]);
     let mut partial = vec![sample_value; n];
     for &u in sorted.iter().rev() {
         lower[u] = child[u]
             .iter()
             .map(|(v, e)| T::monoidize(&partial[*v], e))
-            .fold(lower[u], |acc, a| T::op(&acc, &a));
+            .fold(lower[u].clone(), |acc, a| T::Monoid::op(&acc, &a));
         partial[u] = T::valueize(&lower[u], &vertex[u]);
     }
 
     for &u in &sorted {
         let (p, pe) = parent[u];
         let mut suffix = if u == p {
-            T::e()
+            T::Monoid::e()
         } else {
             T::monoidize(&T::valueize(&upper[u], &vertex[p]), &pe)
         };
         for &(v, e) in child[u].iter().rev() {
-            upper[v] = T::op(&upper[v], &suffix);
-            suffix = T::op(&suffix, &T::monoidize(&partial[v], &e));
+            upper[v] = T::Monoid::op(&upper[v], &suffix);
+            suffix = T::Monoid::op(&suffix, &T::monoidize(&partial[v], &e));
         }
-        let mut prefix = T::e();
+        let mut prefix = T::Monoid::e();
         for &(v, e) in &child[u] {
-            upper[v] = T::op(&upper[v], &prefix);
-            prefix = T::op(&prefix, &T::monoidize(&partial[v], &e));
+            upper[v] = T::Monoid::op(&upper[v], &prefix);
+            prefix = T::Monoid::op(&prefix, &T::monoidize(&partial[v], &e));
         }
     }
 
@@ -72,11 +74,11 @@ pub fn rerooting<T: ValMonoid>(
         .map(|i| {
             let (p, e) = parent[i];
             let x = if i == p {
-                T::e()
+                T::Monoid::e()
             } else {
                 T::monoidize(&T::valueize(&upper[i], &vertex[p]), &e)
             };
-            T::valueize(&T::op(&lower[i], &x), &vertex[i])
+            T::valueize(&T::Monoid::op(&lower[i], &x), &vertex[i])
         })
         .collect::<Vec<_>>()
 }